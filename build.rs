@@ -0,0 +1,180 @@
+//! Generates one regression test per day, part and input file with a recorded answer, for
+//! `src/regression.rs` to include, and a version for each day's solver, for `src/cache.rs`.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Shared with the library, so the cache hashes inputs the same way as solvers are hashed here.
+#[path = "src/fnv.rs"]
+mod fnv;
+
+use fnv::{fnv1a, FNV_OFFSET};
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("Cargo always sets `OUT_DIR` for build scripts.");
+
+    fs::write(
+        Path::new(&out_dir).join("regression.rs"),
+        regression_tests(),
+    )
+    .expect("Couldn't write the generated regression tests.");
+    fs::write(
+        Path::new(&out_dir).join("solver_versions.rs"),
+        solver_versions(),
+    )
+    .expect("Couldn't write the generated solver versions.");
+}
+
+fn regression_tests() -> String {
+    println!("cargo:rerun-if-changed=input");
+
+    let mut tests = String::new();
+
+    for (day, dir) in day_dirs() {
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let mut stems = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let stem = path.file_stem()?.to_str()?.to_owned();
+
+                (path.extension()? == "txt").then_some(stem)
+            })
+            .collect::<Vec<_>>();
+        stems.sort();
+
+        for participant in stems {
+            let answers = dir.join(format!("{participant}.answers"));
+            println!("cargo:rerun-if-changed={}", answers.display());
+
+            let Ok(answers) = fs::read_to_string(answers) else {
+                continue;
+            };
+
+            let only_part = only_part(&participant);
+
+            for (part, variant) in [("part1", "One"), ("part2", "Two")] {
+                if only_part.is_some_and(|only| only != part) {
+                    continue;
+                }
+
+                let recorded = answers
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .any(|(key, _)| key.trim() == part);
+
+                if recorded {
+                    let name = format!("day{day}_{part}_{}", identifier(&participant));
+
+                    let _ = writeln!(
+                        tests,
+                        "#[test]\nfn {name}() {{\n    check({day}, Part::{variant}, {participant:?});\n}}\n"
+                    );
+                }
+            }
+        }
+    }
+
+    tests
+}
+
+/// A version for each day with a source file in `src/days/`, hashed from that file and the shared
+/// library code in `src/`. Any change to either gives the day a new version, even one that can't
+/// change its answers, but it's never wrong to solve again.
+fn solver_versions() -> String {
+    println!("cargo:rerun-if-changed=src");
+
+    let sources = |dir: &str| {
+        let mut files = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+
+                (path.extension()? == "rs").then_some(path)
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+
+        files
+    };
+
+    let shared = sources("src").iter().fold(FNV_OFFSET, |hash, path| {
+        fnv1a(hash, &fs::read(path).unwrap_or_default())
+    });
+
+    let mut versions = String::from("const SOLVER_VERSIONS: &[(u8, &str)] = &[\n");
+
+    let mut days = sources("src/days")
+        .into_iter()
+        .filter_map(|path| {
+            let day: u8 = path
+                .file_stem()?
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+
+            Some((day, path))
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+
+    for (day, path) in days {
+        let version = fnv1a(shared, &fs::read(path).unwrap_or_default());
+
+        let _ = writeln!(versions, "    ({day}, \"{version:016x}\"),");
+    }
+
+    versions.push_str("];\n");
+    versions
+}
+
+/// Every `input/dayN` directory, sorted by day.
+fn day_dirs() -> Vec<(u8, PathBuf)> {
+    let mut dirs = fs::read_dir("input")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+
+            path.is_dir().then_some((day, path))
+        })
+        .collect::<Vec<_>>();
+    dirs.sort();
+
+    dirs
+}
+
+/// The one part an input named like `xmp.p2` is solved for, matching `runner::only_part`.
+fn only_part(name: &str) -> Option<&'static str> {
+    match name.rsplit_once('.')?.1 {
+        "p1" => Some("part1"),
+        "p2" => Some("part2"),
+        _ => None,
+    }
+}
+
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() {
+                char.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+//! Counting of what the solvers allocate, when built with the `count-allocations` feature, which
+//! swaps in a global allocator that keeps a tally for each thread.
+
+use std::fmt;
+
+/// Whether allocations are being counted in this build.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+/// What was allocated while measuring something.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most that was allocated and not yet freed at any one time.
+    pub peak_bytes: u64,
+}
+
+impl fmt::Display for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// A number of bytes, shown in the largest unit that keeps it at least 1.
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread. Gives `None` for the allocations unless
+/// they're being counted.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let start = counting::start();
+        let result = f();
+
+        (result, Some(counting::since(start)))
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Allocations;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    // Kept per thread, so parts being solved side by side don't count each other's allocations.
+    // Live bytes can go negative, when a thread frees what another one allocated.
+    thread_local! {
+        static COUNT: Cell<u64> = const { Cell::new(0) };
+        static BYTES: Cell<u64> = const { Cell::new(0) };
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    struct CountingAllocator;
+
+    // SAFETY: Everything is passed straight on to the system allocator. The counters are const
+    // thread-locals without destructors, so touching them never allocates, and `try_with` skips
+    // counting on a thread that's being torn down.
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record_allocation(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record_allocation(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record_free(layout.size());
+            record_allocation(new_size);
+            System.realloc(ptr, layout, new_size)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record_free(layout.size());
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    fn record_allocation(size: usize) {
+        let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+        let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + size as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+
+    fn record_free(size: usize) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+    }
+
+    /// This thread's counters when measuring started.
+    pub(super) struct Start {
+        count: u64,
+        bytes: u64,
+        live: i64,
+    }
+
+    pub(super) fn start() -> Start {
+        let live = LIVE.with(Cell::get);
+        PEAK.with(|peak| peak.set(live));
+
+        Start {
+            count: COUNT.with(Cell::get),
+            bytes: BYTES.with(Cell::get),
+            live,
+        }
+    }
+
+    pub(super) fn since(start: Start) -> Allocations {
+        Allocations {
+            count: COUNT.with(Cell::get) - start.count,
+            bytes: BYTES.with(Cell::get) - start.bytes,
+            peak_bytes: (PEAK.with(Cell::get) - start.live).max(0) as u64,
+        }
+    }
+}
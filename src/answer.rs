@@ -0,0 +1,112 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// A puzzle answer. Most are integers, but some puzzles want negative numbers, numbers too big for
+/// 64 bits, or text.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    I64(i64),
+    I128(i128),
+    String(String),
+}
+
+impl Answer {
+    /// The answer as an integer, or `None` if it's text.
+    pub fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Self::I64(integer) => Some(integer.into()),
+            Self::I128(integer) => Some(integer),
+            Self::String(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I64(integer) => write!(f, "{integer}"),
+            Self::I128(integer) => write!(f, "{integer}"),
+            Self::String(string) => write!(f, "{string}"),
+        }
+    }
+}
+
+/// Integers compare by value, whichever width they were stored at.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::String(a), Self::String(b)) => a == b,
+            _ => self
+                .as_i128()
+                .zip(other.as_i128())
+                .is_some_and(|(a, b)| a == b),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Reads back an answer as it was displayed: as an integer if it looks like one, otherwise as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(integer) = s.parse::<i64>() {
+            Self::I64(integer)
+        } else if let Ok(integer) = s.parse::<i128>() {
+            Self::I128(integer)
+        } else {
+            Self::String(s.to_owned())
+        })
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Self::I64(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Self::I64(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Self::I128(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Self::I64(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Self::I64(value),
+            Err(_) => Self::I128(value.into()),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        (value as u64).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    runner::{Outcome, PartResult},
+    Answer, AocError, Part,
+};
+
+/// The known-good answers for one participant's input on one day, recorded in
+/// `input/dayN/<initials>.answers` as `part1: <answer>` and `part2: <answer>` lines. Either line
+/// may be left out if that answer isn't known yet.
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl ExpectedAnswers {
+    /// Loads the recorded answers, treating a missing file as nothing being known yet.
+    pub fn load(day: u8, participant: &str) -> Result<Self, AocError> {
+        Self::load_from(&Self::path(Path::new("input"), day, participant))
+    }
+
+    /// Where `participant`'s answers for `day` are recorded, under `input_dir`.
+    pub fn path(input_dir: &Path, day: u8, participant: &str) -> PathBuf {
+        input_dir
+            .join(format!("day{day}"))
+            .join(format!("{participant}.answers"))
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AocError::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), AocError> {
+        fs::write(path, self.to_string()).map_err(|source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// Writes the answers back out in the same shape they're read in.
+impl fmt::Display for ExpectedAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in [Part::One, Part::Two] {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "part{part}: {answer}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ExpectedAnswers {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = Self::default();
+
+        for (index, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some((key, answer)) = line.split_once(':') else {
+                return Err(AocError::parse(
+                    index + 1,
+                    1,
+                    "Expected `part1:` or `part2:`.",
+                ));
+            };
+
+            let slot = match key.trim() {
+                "part1" => &mut expected.part1,
+                "part2" => &mut expected.part2,
+                key => {
+                    return Err(AocError::parse(
+                        index + 1,
+                        1,
+                        format!("`{key}` is not a part, expected `part1` or `part2`."),
+                    ))
+                }
+            };
+
+            let Ok(answer) = answer.trim().parse();
+            *slot = Some(answer);
+        }
+
+        Ok(expected)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// There's no recorded answer to check against.
+    Unknown,
+}
+
+impl Verdict {
+    /// A part that failed or ran out of time fails whether or not its answer is known, so a
+    /// crashing solver is never waved through as unknown.
+    pub fn of(expected: Option<&Answer>, outcome: &Outcome) -> Self {
+        match (expected, outcome) {
+            (_, Outcome::Failed(_) | Outcome::TimedOut(_)) => Self::Fail,
+            (None, _) | (_, Outcome::Skipped) => Self::Unknown,
+            (Some(expected), Outcome::Solved(answer)) if answer == expected => Self::Pass,
+            (Some(_), _) => Self::Fail,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Checks each result against the recorded answers, printing one verdict line per result.
+/// Returns whether nothing failed.
+pub fn verify(results: &[PartResult]) -> bool {
+    let mut all_passed = true;
+
+    for result in results {
+        let expected = ExpectedAnswers::load(result.day, &result.participant);
+
+        let (verdict, detail) = match &expected {
+            Ok(expected) => {
+                let expected = expected.get(result.part);
+                let verdict = Verdict::of(expected, &result.outcome);
+
+                let detail = match (verdict, expected) {
+                    (Verdict::Fail, Some(expected)) => {
+                        format!("expected {expected}, got {}", result.outcome)
+                    }
+                    _ => result.outcome.to_string(),
+                };
+
+                (verdict, detail)
+            }
+            Err(error) => (Verdict::Fail, format!("couldn't load answers: {error}")),
+        };
+
+        all_passed &= verdict != Verdict::Fail;
+
+        println!(
+            "Day {} part {} {}: {verdict} ({detail})",
+            result.day,
+            result.part,
+            result.participant.to_uppercase()
+        );
+    }
+
+    all_passed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn judges_solved_and_skipped_parts_by_the_recorded_answer() {
+        let answer = Answer::from(42);
+        let solved = Outcome::Solved(answer.clone());
+
+        assert_eq!(Verdict::of(Some(&answer), &solved), Verdict::Pass);
+        assert_eq!(Verdict::of(Some(&Answer::from(7)), &solved), Verdict::Fail);
+        assert_eq!(Verdict::of(None, &solved), Verdict::Unknown);
+        assert_eq!(
+            Verdict::of(Some(&answer), &Outcome::Skipped),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn fails_parts_that_failed_or_timed_out_without_a_recorded_answer() {
+        let failed = Outcome::Failed(Arc::new(AocError::solve("Something went wrong.")));
+        let timed_out = Outcome::TimedOut(Duration::from_secs(1));
+
+        for outcome in [failed, timed_out] {
+            assert_eq!(Verdict::of(None, &outcome), Verdict::Fail);
+            assert_eq!(
+                Verdict::of(Some(&Answer::from(42)), &outcome),
+                Verdict::Fail
+            );
+        }
+    }
+}
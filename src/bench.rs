@@ -0,0 +1,144 @@
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    isolate::catch_panic,
+    runner::{self, Outcome, RunOptions, Solver},
+    AocError, Part, Solution,
+};
+
+/// Summary statistics over repeated timings of the same step.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+/// Timings for every step of one day, for one participant.
+pub struct StepStats {
+    pub parse: Stats,
+    /// Only the parts the run was asked for.
+    pub parts: Vec<(Part, Stats)>,
+}
+
+pub struct Benchmark {
+    pub day: u8,
+    pub participant: String,
+    pub stats: Result<StepStats, Arc<AocError>>,
+}
+
+/// Times parsing and each selected part separately, `runs` times over, for each selected input,
+/// leaving out the other part for an input [only for one](runner::only_part). Reading the input
+/// file happens once up front and isn't timed, and participants without an input for `day` are
+/// left out.
+pub fn bench_day<S: Solution>(day: u8, options: &RunOptions, runs: usize) -> Vec<Benchmark> {
+    runner::read_inputs(day, options)
+        .into_iter()
+        .filter_map(|(name, contents)| {
+            let solvers = options.solvers::<S>(&name);
+            let stats = match contents {
+                Ok(contents) => catch_panic(|| bench_input::<S>(&contents, &solvers, runs.max(1)))
+                    .unwrap_or_else(|error| Err((None, error)))
+                    .map_err(|(part, error)| Arc::new(error.for_participant(day, part, &name))),
+                Err(Outcome::Failed(error)) => Err(error),
+                Err(_) => return None,
+            };
+
+            Some(Benchmark {
+                day,
+                participant: name,
+                stats,
+            })
+        })
+        .collect()
+}
+
+fn bench_input<S: Solution>(
+    input: &str,
+    solvers: &[(Part, Solver<S::Input>)],
+    runs: usize,
+) -> Result<StepStats, (Option<Part>, AocError)> {
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut part_samples = vec![Vec::with_capacity(runs); solvers.len()];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(input).map_err(|error| (None, error))?;
+        parse_samples.push(start.elapsed());
+
+        for ((part, solve), samples) in solvers.iter().zip(&mut part_samples) {
+            let start = Instant::now();
+            solve(&parsed).map_err(|error| (Some(*part), error))?;
+            samples.push(start.elapsed());
+        }
+    }
+
+    Ok(StepStats {
+        parse: Stats::from_samples(&mut parse_samples),
+        parts: solvers
+            .iter()
+            .zip(&mut part_samples)
+            .map(|((part, _), samples)| (*part, Stats::from_samples(samples)))
+            .collect(),
+    })
+}
+
+pub fn print_benchmarks(benchmarks: &[Benchmark]) {
+    for benchmark in benchmarks {
+        let initials = benchmark.participant.to_uppercase();
+
+        match &benchmark.stats {
+            Ok(stats) => {
+                println!("Day {} {initials} parse  {}", benchmark.day, stats.parse);
+
+                for (part, part_stats) in &stats.parts {
+                    println!("Day {} {initials} part {part} {part_stats}", benchmark.day);
+                }
+            }
+            Err(error) => println!("Day {} {initials} FAILED - {error}", benchmark.day),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    time::Duration,
+};
+
+use aoc2023::{
+    answers, bench,
+    cache::Cache,
+    config::Config,
+    days::{self, Day},
+    examples,
+    fetch::{self, Fetched},
+    report::{self, Format},
+    runner::{Inputs, Outcome, RunOptions},
+    scaffold, submit, watch, Answer, Part,
+};
+
+const USAGE: &str = "\
+Usage: aoc [verify | bench [--runs <n>] | watch [--interval <seconds>]] [options]
+           [all | <day> | <first>-<last>]...
+       aoc fetch [--participant <initials>] <day | <first>-<last>>...
+       aoc submit [--participant <initials>] --part <1|2> [--answer <answer>] <day>
+       aoc new-day <day>
+       aoc examples --page <path> <day>
+
+Options:
+    --part <1|2>             Only solve one part.
+    --participant <initials> Only solve these participants' inputs. May be repeated, or given a
+                             comma-separated list.
+    --input <path>           Solve the input at <path> instead. `-` reads it from stdin.
+    --stdin                  Same as `--input -`.
+    --format <format>        Print answers as text (the default), json, csv or markdown.
+    --jobs <n>               Solve up to <n> inputs at once. Defaults to one per core, except
+                             when benchmarking, which always runs one at a time.
+    --timeout <seconds>      Give up on any part still running after <seconds>, reporting it as
+                             TIMEOUT.
+    --warn-normalised        Warn about inputs that had a byte order mark, CRLF line endings or
+                             trailing whitespace removed before solving.
+    --answer <answer>        The answer to submit, instead of solving the part to get it.
+    --page <path>            The puzzle's page, saved from the site, to take the examples from.
+    --no-cache               Solve every part, rather than reusing answers from earlier runs with
+                             the same input and solver code, and don't cache the new answers.
+    --clear-cache            Forget every cached answer before solving.
+
+`fetch` and `submit` use the session in the config file, and fall back to its `initials` when
+no participant is given. See `aoc2023::config` for its location and format.
+
+Inputs named like `xmp.p1.txt` or `xmp.p2.txt` are only solved for that part, for puzzles that
+give each part its own example.
+
+Build with `--features count-allocations` to also report what each part allocates.";
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+enum Mode {
+    Solve,
+    Verify,
+    Bench { runs: usize },
+    Watch { interval: Duration },
+    Fetch,
+    Submit { answer: Option<Answer> },
+    NewDay,
+    Examples { page: Option<PathBuf> },
+}
+
+struct Args {
+    mode: Mode,
+    selections: Vec<RangeInclusive<u8>>,
+    options: RunOptions,
+    format: Format,
+    use_cache: bool,
+    clear_cache: bool,
+}
+
+/// Runs one day (`aoc 5`), a range of days (`aoc 3-7`), or every day (`aoc` / `aoc all`).
+/// `aoc verify ...` checks the answers against the recorded ones instead of printing them, and
+/// exits non-zero if any don't match. `aoc bench ...` times each step over repeated runs.
+/// `aoc watch ...` solves again whenever an input changes, showing how the answers changed.
+/// `aoc fetch ...` downloads inputs that aren't on disk yet, using the session in the config.
+/// `aoc submit ...` sends an answer to the site, unless it's already known to be wrong.
+/// `aoc new-day N` sets up everything a new day needs, to be run from the repository's root.
+/// `aoc examples ...` saves the examples and their answers from a saved puzzle page as inputs.
+fn main() {
+    let Args {
+        mode,
+        selections,
+        mut options,
+        format,
+        use_cache,
+        clear_cache,
+    } = parse_args(env::args().skip(1).collect()).unwrap_or_else(|message| {
+        eprintln!("{message}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    match mode {
+        Mode::Fetch => {
+            if !fetch_inputs(&selections, &options.inputs) {
+                process::exit(1);
+            }
+
+            return;
+        }
+        Mode::Submit { answer } => {
+            if !submit_answer(&selections, &options, answer) {
+                process::exit(1);
+            }
+
+            return;
+        }
+        Mode::NewDay => {
+            if !new_day(&selections) {
+                process::exit(1);
+            }
+
+            return;
+        }
+        Mode::Examples { page } => {
+            if !save_examples(&selections, page) {
+                process::exit(1);
+            }
+
+            return;
+        }
+        Mode::Solve | Mode::Verify | Mode::Bench { .. } | Mode::Watch { .. } => {}
+    }
+
+    let selected_days: Vec<&Day> = if selections.is_empty() {
+        days::DAYS.iter().collect()
+    } else {
+        days::DAYS
+            .iter()
+            .filter(|day| {
+                selections
+                    .iter()
+                    .any(|selection| selection.contains(&day.number))
+            })
+            .collect()
+    };
+
+    if selected_days.is_empty() {
+        eprintln!("None of the selected days have been solved yet.");
+        process::exit(1);
+    }
+
+    // Benchmarks have to solve every time, so only the other modes touch the cache.
+    if !matches!(mode, Mode::Bench { .. }) {
+        options.cache = open_cache(use_cache, clear_cache).unwrap_or_else(|message| {
+            eprintln!("{message}");
+            process::exit(1);
+        });
+    }
+
+    match mode {
+        Mode::Solve => {
+            let results = days::run(&selected_days, &options);
+
+            report::print(&results, format);
+        }
+        Mode::Verify => {
+            let results = days::run(&selected_days, &options);
+
+            if !answers::verify(&results) {
+                process::exit(1);
+            }
+        }
+        Mode::Bench { runs } => {
+            let benchmarks: Vec<_> = selected_days
+                .into_iter()
+                .flat_map(|day| day.bench(&options, runs))
+                .collect();
+
+            bench::print_benchmarks(&benchmarks);
+        }
+        Mode::Watch { interval } => watch::watch(&selected_days, &options, format, interval),
+        Mode::Fetch | Mode::Submit { .. } | Mode::NewDay | Mode::Examples { .. } => {
+            unreachable!("Modes that don't solve anything were handled above.")
+        }
+    }
+}
+
+fn parse_args(args: Vec<String>) -> Result<Args, String> {
+    let mut args = args.into_iter().peekable();
+
+    let mut mode = match args.peek().map(String::as_str) {
+        Some("verify") => Mode::Verify,
+        Some("bench") => Mode::Bench {
+            runs: DEFAULT_BENCH_RUNS,
+        },
+        Some("watch") => Mode::Watch {
+            interval: DEFAULT_WATCH_INTERVAL,
+        },
+        Some("fetch") => Mode::Fetch,
+        Some("submit") => Mode::Submit { answer: None },
+        Some("new-day") => Mode::NewDay,
+        Some("examples") => Mode::Examples { page: None },
+        _ => Mode::Solve,
+    };
+
+    if !matches!(mode, Mode::Solve) {
+        args.next();
+    }
+
+    let mut selections = Vec::new();
+    let mut options = RunOptions::default();
+    let mut participants = Vec::new();
+    let mut input_path = None;
+    let mut format = Format::default();
+    let mut use_cache = true;
+    let mut clear_cache = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or(format!("`{flag}` needs a value."));
+
+        match (arg.as_str(), &mut mode) {
+            ("--runs", Mode::Bench { runs }) => {
+                let value = value("--runs")?;
+
+                *runs = value
+                    .parse()
+                    .map_err(|_| format!("`{value}` is not a valid number of runs."))?;
+            }
+            ("--interval", Mode::Watch { interval }) => {
+                let value = value("--interval")?;
+
+                *interval = parse_seconds(&value)?;
+            }
+            ("--answer", Mode::Submit { answer }) => {
+                let Ok(value) = value("--answer")?.parse();
+                *answer = Some(value);
+            }
+            ("--page", Mode::Examples { page }) => *page = Some(PathBuf::from(value("--page")?)),
+            ("--part", _) => {
+                options.part = Some(match value("--part")?.as_str() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    part => return Err(format!("`{part}` is not a part, expected 1 or 2.")),
+                });
+            }
+            ("--participant", _) => {
+                participants.extend(
+                    value("--participant")?
+                        .split(',')
+                        .filter(|initials| !initials.is_empty())
+                        .map(str::to_lowercase),
+                );
+            }
+            ("--input", _) => input_path = Some(value("--input")?),
+            ("--stdin", _) => input_path = Some(String::from("-")),
+            ("--format", _) => format = value("--format")?.parse()?,
+            ("--warn-normalised", _) => options.warn_normalised = true,
+            ("--no-cache", _) => use_cache = false,
+            ("--clear-cache", _) => clear_cache = true,
+            ("--timeout", _) => {
+                let value = value("--timeout")?;
+
+                options.timeout = Some(parse_seconds(&value)?);
+            }
+            ("--jobs", _) => {
+                let value = value("--jobs")?;
+
+                options.jobs = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|&jobs| jobs > 0)
+                        .ok_or(format!("`{value}` is not a valid number of jobs."))?,
+                );
+            }
+            (flag, _) if flag.starts_with("--") => return Err(format!("Unknown option `{flag}`.")),
+            _ => selections.push(parse_selection(&arg)?),
+        }
+    }
+
+    options.inputs = match input_path {
+        Some(_) if !participants.is_empty() => {
+            return Err(String::from(
+                "`--participant` can't be combined with `--input` or `--stdin`.",
+            ))
+        }
+        Some(path) if path == "-" && matches!(mode, Mode::Watch { .. }) => {
+            return Err(String::from(
+                "Input from stdin can't be watched for changes.",
+            ))
+        }
+        Some(path) if path == "-" => {
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|error| format!("Couldn't read the input from stdin: {error}"))?;
+
+            Inputs::Text {
+                name: String::from("stdin"),
+                contents,
+            }
+        }
+        Some(path) => Inputs::File(PathBuf::from(path)),
+        None => Inputs::Participants(participants),
+    };
+
+    Ok(Args {
+        mode,
+        selections,
+        options,
+        format,
+        use_cache,
+        clear_cache,
+    })
+}
+
+/// The cache to solve with, cleared first if asked. `None` if it's not wanted, or can't be read.
+fn open_cache(use_cache: bool, clear_cache: bool) -> Result<Option<Arc<Cache>>, String> {
+    let path = Cache::default_path();
+
+    if clear_cache {
+        Cache::clear(&path).map_err(|error| format!("Couldn't clear the cache: {error}"))?;
+    }
+
+    if !use_cache {
+        return Ok(None);
+    }
+
+    match Cache::open(&path) {
+        Ok(cache) => Ok(Some(Arc::new(cache))),
+        Err(error) => {
+            eprintln!("Warning: solving without the cache, as {error}");
+            Ok(None)
+        }
+    }
+}
+
+/// The config, and the one participant `fetch` or `submit` is acting for.
+fn config_and_initials(inputs: &Inputs) -> Option<(Config, String)> {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            return None;
+        }
+    };
+
+    let initials = match inputs {
+        Inputs::Participants(participants) => match &participants[..] {
+            [] => config.initials.clone(),
+            [initials] => Some(initials.clone()),
+            _ => {
+                eprintln!("Only one participant can be given.");
+                return None;
+            }
+        },
+        Inputs::File(_) | Inputs::Text { .. } => {
+            eprintln!("`--input` and `--stdin` can't be used here.");
+            return None;
+        }
+    };
+
+    let Some(initials) = initials else {
+        eprintln!(
+            "Say who this is for with `--participant`, or set `initials` in {}.",
+            config.path.display()
+        );
+        return None;
+    };
+
+    Some((config, initials))
+}
+
+/// Fetches the selected days' inputs for one participant. Returns whether every input is now on
+/// disk.
+fn fetch_inputs(selections: &[RangeInclusive<u8>], inputs: &Inputs) -> bool {
+    let Some((config, initials)) = config_and_initials(inputs) else {
+        return false;
+    };
+
+    if selections.is_empty() {
+        eprintln!("Say which days to fetch.");
+        return false;
+    }
+
+    let mut fetched_all = true;
+
+    for day in 1..=25 {
+        if !selections.iter().any(|selection| selection.contains(&day)) {
+            continue;
+        }
+
+        match fetch::fetch(day, &initials, &config, Path::new("input")) {
+            Ok(Fetched::Cached(path)) => println!("Day {day}: already have {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved to {}", path.display()),
+            Err(error) => {
+                fetched_all = false;
+                eprintln!("Day {day}: {error}");
+            }
+        }
+    }
+
+    fetched_all
+}
+
+/// Submits one part's answer for one participant, solving it first if no answer was given.
+/// Returns whether the answer was right, or already recorded as right.
+fn submit_answer(
+    selections: &[RangeInclusive<u8>],
+    options: &RunOptions,
+    answer: Option<Answer>,
+) -> bool {
+    let Some((config, initials)) = config_and_initials(&options.inputs) else {
+        return false;
+    };
+
+    let (day, part) = match (selections, options.part) {
+        ([selection], Some(part)) if selection.start() == selection.end() => {
+            (*selection.start(), part)
+        }
+        _ => {
+            eprintln!("Say which day to submit an answer for, and which part with `--part`.");
+            return false;
+        }
+    };
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some(solved_day) = days::get(day) else {
+                eprintln!("Day {day} hasn't been solved yet, so give the answer with `--answer`.");
+                return false;
+            };
+
+            let options = RunOptions {
+                inputs: Inputs::Participants(vec![initials.clone()]),
+                ..options.clone()
+            };
+
+            // Only the part being submitted is solved, so there's more than one result only if
+            // there's both `<initials>.txt` and `<initials>.pN.txt` for it.
+            let result = match &solved_day.run(&options)[..] {
+                [result] => result.outcome.clone(),
+                [] => return false,
+                results => {
+                    let inputs: Vec<String> = results
+                        .iter()
+                        .map(|result| format!("{}.txt", result.participant))
+                        .collect();
+
+                    eprintln!(
+                        "Day {day} part {part} has more than one input for {} ({}), so give the \
+                         answer with `--answer`.",
+                        initials.to_uppercase(),
+                        inputs.join(", ")
+                    );
+                    return false;
+                }
+            };
+
+            match result {
+                Outcome::Solved(answer) => answer,
+                outcome => {
+                    eprintln!("Day {day} part {part}: {outcome}");
+                    return false;
+                }
+            }
+        }
+    };
+
+    match submit::submit(
+        day,
+        part,
+        &answer,
+        &initials,
+        &config,
+        Path::new("input"),
+        &submit::default_history_dir(),
+    ) {
+        Ok(submission) => {
+            println!(
+                "Day {day} part {part} {}: {answer} - {submission}",
+                initials.to_uppercase()
+            );
+
+            matches!(
+                submission,
+                submit::Submission::Correct | submit::Submission::AlreadyRecorded
+            )
+        }
+        Err(error) => {
+            eprintln!("Day {day} part {part}: {error}");
+            false
+        }
+    }
+}
+
+/// Scaffolds the one selected day. Returns whether that worked.
+fn new_day(selections: &[RangeInclusive<u8>]) -> bool {
+    let day = match selections {
+        [selection]
+            if selection.start() == selection.end() && (1..=25).contains(selection.start()) =>
+        {
+            *selection.start()
+        }
+        _ => {
+            eprintln!("Say which day from 1 to 25 to set up.");
+            return false;
+        }
+    };
+
+    match scaffold::new_day(Path::new("."), day) {
+        Ok(touched) => {
+            touched
+                .iter()
+                .for_each(|path| println!("Wrote {}", path.display()));
+            println!("Day {day} is ready. Paste the example into input/day{day}/xmp.txt to start.");
+
+            true
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            false
+        }
+    }
+}
+
+/// Saves the examples from a saved puzzle page for the one selected day. Returns whether that
+/// worked.
+fn save_examples(selections: &[RangeInclusive<u8>], page: Option<PathBuf>) -> bool {
+    let (day, page) = match (selections, page) {
+        ([selection], Some(page)) if selection.start() == selection.end() => {
+            (*selection.start(), page)
+        }
+        _ => {
+            eprintln!("Say which day the page is for, and where it's saved with `--page`.");
+            return false;
+        }
+    };
+
+    let page = match fs::read_to_string(&page) {
+        Ok(page) => page,
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", page.display());
+            return false;
+        }
+    };
+
+    let found = examples::extract(&page);
+
+    if found.is_empty() {
+        eprintln!("The page doesn't have any examples.");
+        return false;
+    }
+
+    for example in &found {
+        let answers = [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| Some(format!("part {part}: {}", example.answers.get(part)?)))
+            .collect::<Vec<_>>();
+
+        if answers.is_empty() {
+            println!("{}: no answers found", example.name.to_uppercase());
+        } else {
+            println!("{}: {}", example.name.to_uppercase(), answers.join(", "));
+        }
+    }
+
+    match examples::save(&found, &Path::new("input").join(format!("day{day}"))) {
+        Ok(written) => {
+            written
+                .iter()
+                .for_each(|path| println!("Wrote {}", path.display()));
+
+            true
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            false
+        }
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or(format!("`{value}` is not a valid number of seconds."))
+}
+
+fn parse_selection(arg: &str) -> Result<RangeInclusive<u8>, String> {
+    if arg == "all" {
+        return Ok(u8::MIN..=u8::MAX);
+    }
+
+    let parse_day = |day: &str| {
+        day.parse::<u8>()
+            .map_err(|_| format!("`{day}` is not a valid day number."))
+    };
+
+    match arg.split_once('-') {
+        Some((first, last)) => Ok(parse_day(first)?..=parse_day(last)?),
+        None => {
+            let day = parse_day(arg)?;
+
+            Ok(day..=day)
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::{
+    fnv::{fnv1a, FNV_OFFSET},
+    Answer, AocError, Part,
+};
+
+// `SOLVER_VERSIONS`, generated by `build.rs` from the sources.
+include!(concat!(env!("OUT_DIR"), "/solver_versions.rs"));
+
+/// A string that changes whenever the code behind `day`'s solver might have: a hash of the day's
+/// own source, and of the library code shared by every day. `None` for a day without a source file
+/// in `src/days/`.
+pub fn solver_version(day: u8) -> Option<&'static str> {
+    SOLVER_VERSIONS
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, version)| version)
+}
+
+/// Answers from earlier runs, kept in a file of `<key>: <answer>` lines, where the key covers the
+/// day, the part, a hash of the input and the [solver's version](solver_version). A changed input
+/// or solver simply never matches its old answers again, so nothing ever needs invalidating.
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    answers: Mutex<HashMap<String, Answer>>,
+}
+
+impl Cache {
+    /// Where `aoc` keeps its cache. It's next to the build, rather than the inputs, as `build.rs`
+    /// watches those, and a cache is as disposable as the build anyway.
+    pub fn default_path() -> PathBuf {
+        Path::new("target").join("answers.cache")
+    }
+
+    /// Loads the cache at `path`, which starts out empty if there's no file there yet. Lines that
+    /// don't make sense are ignored, as they'd only ever cost solving again.
+    pub fn open(path: &Path) -> Result<Self, AocError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+
+        let answers = contents
+            .lines()
+            .filter_map(|line| line.split_once(": "))
+            .map(|(key, answer)| {
+                let Ok(answer) = unescape(answer).parse();
+                (key.to_owned(), answer)
+            })
+            .collect();
+
+        Ok(Self {
+            path: path.to_owned(),
+            answers: Mutex::new(answers),
+        })
+    }
+
+    /// Deletes the cache at `path`, if there is one.
+    pub fn clear(path: &Path) -> Result<(), AocError> {
+        match fs::remove_file(path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(AocError::Io {
+                path: path.to_owned(),
+                source: error,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// The answer for one part of `day` with this `input`, if the current solver has given one.
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<Answer> {
+        let key = key(day, part, input)?;
+
+        self.answers.lock().ok()?.get(&key).cloned()
+    }
+
+    /// Remembers an answer, adding it to the file straight away so it survives even if the run
+    /// doesn't finish.
+    pub fn insert(
+        &self,
+        day: u8,
+        part: Part,
+        input: &str,
+        answer: &Answer,
+    ) -> Result<(), AocError> {
+        let Some(key) = key(day, part, input) else {
+            return Ok(());
+        };
+
+        let io_error = |source| AocError::Io {
+            path: self.path.clone(),
+            source,
+        };
+
+        let line = format!("{key}: {}\n", escape(&answer.to_string()));
+
+        if let Ok(mut answers) = self.answers.lock() {
+            if answers.get(&key) == Some(answer) {
+                return Ok(());
+            }
+
+            answers.insert(key, answer.clone());
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(io_error)
+    }
+}
+
+fn key(day: u8, part: Part, input: &str) -> Option<String> {
+    let version = solver_version(day)?;
+
+    Some(format!(
+        "day {day} part {part} input {:016x} solver {version}",
+        fnv1a(FNV_OFFSET, input.as_bytes())
+    ))
+}
+
+/// Keeps text answers spanning several lines to one line of the file.
+fn escape(answer: &str) -> String {
+    answer.replace('\\', r"\\").replace('\n', r"\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+
+    while let Some(char) = chars.next() {
+        match (char, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            (char, _) => unescaped.push(char),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn keys_answers_by_day_part_input_and_solver() {
+        let key1 = key(1, Part::One, "a\n").unwrap();
+
+        assert!(key1.starts_with("day 1 part 1 input "));
+        assert!(key1.ends_with(&format!("solver {}", solver_version(1).unwrap())));
+
+        assert_eq!(key(1, Part::One, "a\n"), Some(key1.clone()));
+        assert_ne!(key(1, Part::One, "b\n"), Some(key1.clone()));
+        assert_ne!(key(1, Part::Two, "a\n"), Some(key1.clone()));
+        assert_ne!(key(2, Part::One, "a\n"), Some(key1));
+    }
+
+    #[test]
+    fn has_no_key_for_a_day_without_a_solver() {
+        assert_eq!(solver_version(26), None);
+        assert_eq!(key(26, Part::One, "a\n"), None);
+    }
+
+    #[test]
+    fn escapes_answers_onto_one_line_and_back() {
+        for answer in [
+            "1234",
+            "#..#\n.##.\n",
+            r"back\slash",
+            r"\n not a newline",
+            "\\\n",
+        ] {
+            let escaped = escape(answer);
+
+            assert!(!escaped.contains('\n'));
+            assert_eq!(unescape(&escaped), answer);
+        }
+    }
+
+    #[test]
+    fn keeps_answers_across_opening_the_cache_again() {
+        let path = env::temp_dir()
+            .join(format!("aoc2023-cache-{}", process::id()))
+            .join("answers.cache");
+        Cache::clear(&path).unwrap();
+
+        let text = Answer::from(String::from("#.\n.#"));
+
+        let cache = Cache::open(&path).unwrap();
+        cache
+            .insert(1, Part::One, "a\n", &Answer::from(42))
+            .unwrap();
+        cache.insert(1, Part::Two, "a\n", &text).unwrap();
+
+        let cache = Cache::open(&path).unwrap();
+        assert_eq!(cache.get(1, Part::One, "a\n"), Some(Answer::from(42)));
+        assert_eq!(cache.get(1, Part::Two, "a\n"), Some(text));
+        assert_eq!(cache.get(1, Part::One, "b\n"), None);
+
+        Cache::clear(&path).unwrap();
+        assert_eq!(Cache::open(&path).unwrap().get(1, Part::One, "a\n"), None);
+    }
+}
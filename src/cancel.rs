@@ -0,0 +1,58 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::AocError;
+
+/// Shared between the runner and a part running on its own thread, so the runner can ask the part
+/// to stop once it's given up waiting for it.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Makes `token` the one [`check`] looks at on this thread.
+pub(crate) fn set_current(token: CancellationToken) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Whether the runner has given up on the part running on this thread. Always `false` when there's
+/// no time budget.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    })
+}
+
+/// For solvers with long or unbounded loops to call every so often, bailing out with `?` once the
+/// runner has stopped waiting for them.
+pub fn check() -> Result<(), AocError> {
+    if is_cancelled() {
+        Err(AocError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
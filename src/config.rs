@@ -0,0 +1,100 @@
+use std::{env, fs, io, path::PathBuf};
+
+use crate::{parse::Span, AocError};
+
+/// Where the puzzle site lives, unless the config says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Per-user settings for talking to the puzzle site, read from `key = value` lines in the file at
+/// [`Config::path`]. Lines starting with `#` are comments.
+///
+/// ```text
+/// session = 53616c7465645f5f...
+/// initials = jwc
+/// base_url = http://localhost:8080
+/// ```
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Where the config was read from, for error messages.
+    pub path: PathBuf,
+    /// The value of the site's `session` cookie, from a logged-in browser.
+    pub session: Option<String>,
+    /// Whose inputs to fetch when no participant is given.
+    pub initials: Option<String>,
+    pub base_url: String,
+}
+
+impl Config {
+    /// `$AOC2023_CONFIG` if it's set, otherwise `aoc2023/config` in the user's config directory.
+    pub fn path() -> PathBuf {
+        if let Some(path) = env::var_os("AOC2023_CONFIG") {
+            return PathBuf::from(path);
+        }
+
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .unwrap_or_default();
+
+        config_dir.join("aoc2023").join("config")
+    }
+
+    /// Loads the config from [`Config::path`], treating a missing file as an empty one.
+    pub fn load() -> Result<Self, AocError> {
+        let path = Self::path();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(AocError::Io { path, source }),
+        };
+
+        Self::parse(path, &contents)
+    }
+
+    pub fn parse(path: PathBuf, contents: &str) -> Result<Self, AocError> {
+        let mut config = Self {
+            path,
+            session: None,
+            initials: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+        };
+
+        let settings = Span::new(contents)
+            .lines()
+            .map(Span::trim)
+            .filter(|line| !(line.is_empty() || line.as_str().starts_with('#')));
+
+        for setting in settings {
+            let (key, value) = setting
+                .key_value('=')
+                .map_err(|error| config.error(error))?;
+            let value = value.as_str().to_owned();
+
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "initials" => config.initials = Some(value.to_lowercase()),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_owned(),
+                _ => return Err(config.error(key.error(format!("`{key}` is not a setting.")))),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The session token, which anything talking to the site needs.
+    pub fn session(&self) -> Result<&str, AocError> {
+        self.session
+            .as_deref()
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| self.error("No `session` is set."))
+    }
+
+    pub(crate) fn error(&self, message: impl ToString) -> AocError {
+        AocError::Config {
+            path: self.path.clone(),
+            message: message.to_string(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    bench::{self, Benchmark},
+    pool,
+    runner::{self, Outcome, PartResult, RunOptions},
+    Solution,
+};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// A solved day, as known to the `aoc` dispatcher.
+pub struct Day {
+    pub number: u8,
+    solve_input: fn(u8, &RunOptions, &str, Result<String, Outcome>) -> Vec<PartResult>,
+    bench: fn(u8, &RunOptions, usize) -> Vec<Benchmark>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            solve_input: runner::solve_input::<S>,
+            bench: bench::bench_day::<S>,
+        }
+    }
+
+    pub fn bench(&self, options: &RunOptions, runs: usize) -> Vec<Benchmark> {
+        (self.bench)(self.number, options, runs)
+    }
+
+    pub fn run(&self, options: &RunOptions) -> Vec<PartResult> {
+        run(&[self], options)
+    }
+
+    pub fn solve(&self, options: &RunOptions) {
+        runner::print_results(&self.run(options));
+    }
+}
+
+/// Every solved day, in order. New days need adding here to be picked up by `aoc`.
+pub const DAYS: [Day; 9] = [
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8),
+    Day::new::<day9::Day9>(9),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Solves several days at once. Every input of every day is a separate job for the worker pool, so
+/// a slow day doesn't hold up the rest. Results come back ordered by day, then by part and
+/// participant as for a single day.
+pub fn run(days: &[&Day], options: &RunOptions) -> Vec<PartResult> {
+    let jobs: Vec<(&Day, String, Result<String, Outcome>)> = days
+        .iter()
+        .flat_map(|&day| {
+            runner::read_inputs(day.number, options)
+                .into_iter()
+                .map(move |(name, contents)| (day, name, contents))
+        })
+        .collect();
+
+    let results = pool::map(options.jobs(), jobs, |(day, name, contents)| {
+        (day.solve_input)(day.number, options, &name, contents)
+    });
+
+    runner::in_report_order(results.into_iter().flatten().collect())
+}
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{parse::Span, Answer, AocError, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Span::new(input).lines().map(Game::try_from).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer, AocError> {
+        part1(games).map(Answer::from)
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer, AocError> {
+        part2(games).map(Answer::from)
+    }
+}
+
+pub struct Game {
+    id: u64,
+    hands: Vec<Hand>,
+}
+
+struct Hand {
+    cube_counts: HashMap<Color, u64>,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+enum Color {
+    Blue,
+    Green,
+    Red,
+}
+
+impl TryFrom<Span<'_>> for Game {
+    type Error = AocError;
+
+    fn try_from(line: Span<'_>) -> Result<Self, Self::Error> {
+        let (id, game) = line.key_value(':')?;
+        let id = id.strip_prefix("Game")?.parse()?;
+
+        let hands = game
+            .split(';')
+            .map(|hand| {
+                hand.split(',')
+                    .map(|cube_count| {
+                        let (count, color) = cube_count.trim().split_once(' ')?;
+
+                        let color = color
+                            .as_str()
+                            .parse()
+                            .map_err(|()| color.error(format!("`{color}` is not a color.")))?;
+
+                        Ok((color, count.parse()?))
+                    })
+                    .collect::<Result<Hand, AocError>>()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, hands })
+    }
+}
+
+impl FromStr for Game {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Span::new(s).try_into()
+    }
+}
+
+impl FromIterator<(Color, u64)> for Hand {
+    fn from_iter<T: IntoIterator<Item = (Color, u64)>>(iter: T) -> Self {
+        Self {
+            cube_counts: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blue" => Ok(Self::Blue),
+            "green" => Ok(Self::Green),
+            "red" => Ok(Self::Red),
+            _ => Err(()),
+        }
+    }
+}
+
+fn part1(games: &[Game]) -> Result<u64, AocError> {
+    Ok(games
+        .iter()
+        .filter_map(|Game { id, hands }| {
+            hands
+                .iter()
+                .all(|Hand { cube_counts }| {
+                    cube_counts.get(&Color::Blue).copied().unwrap_or_default() <= 14
+                        && cube_counts.get(&Color::Green).copied().unwrap_or_default() <= 13
+                        && cube_counts.get(&Color::Red).copied().unwrap_or_default() <= 12
+                })
+                .then_some(*id)
+        })
+        .sum())
+}
+
+fn part2(games: &[Game]) -> Result<u64, AocError> {
+    Ok(games
+        .iter()
+        .map(|Game { hands, .. }| {
+            let maxima = hands.iter().fold(
+                HashMap::from([(Color::Blue, 0), (Color::Green, 0), (Color::Red, 0)]),
+                |mut maxima, Hand { cube_counts }| {
+                    [Color::Blue, Color::Green, Color::Red]
+                        .into_iter()
+                        .for_each(|color| {
+                            maxima.insert(
+                                color,
+                                maxima[&color]
+                                    .max(cube_counts.get(&color).copied().unwrap_or_default()),
+                            );
+                        });
+
+                    maxima
+                },
+            );
+
+            maxima.values().product::<u64>()
+        })
+        .sum())
+}
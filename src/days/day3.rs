@@ -0,0 +1,164 @@
+use std::collections::BTreeSet;
+
+use crate::{grid::Grid, Answer, AocError, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, AocError> {
+        part1(grid).map(Answer::from)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, AocError> {
+        part2(grid).map(Answer::from)
+    }
+}
+
+fn part1(grid: &Grid<char>) -> Result<u64, AocError> {
+    let mut part_number_sum = 0;
+
+    let mut current_number = String::from("");
+    let mut current_number_index: Option<(usize, usize)> = None;
+
+    grid.iter().for_each(|((x, y), &char)| {
+        if char.is_ascii_digit() {
+            current_number.push(char);
+            current_number_index = current_number_index.or(Some((x, y)));
+        }
+
+        if !current_number.is_empty() && (!char.is_ascii_digit() || x == grid.width() - 1) {
+            let (x, y) = current_number_index
+                .take()
+                .expect("`current_number_index` will always have been set.");
+
+            if span_neighbors(grid, x, y, current_number.len())
+                .into_iter()
+                .any(|(x, y)| {
+                    let char = grid[(x, y)];
+
+                    !(char.is_ascii_digit() || char == '.')
+                })
+            {
+                part_number_sum += current_number
+                    .parse::<u64>()
+                    .expect("We only add ascii digits to `current_number`.");
+            }
+
+            current_number.clear();
+        }
+    });
+
+    Ok(part_number_sum)
+}
+
+fn part2(grid: &Grid<char>) -> Result<u64, AocError> {
+    let gear_ratio_sum: u64 = grid
+        .iter()
+        .filter(|(_, &char)| char == '*')
+        .map(|((x, y), _)| {
+            let mut neighbors = span_neighbors(grid, x, y, 1);
+            neighbors.retain(|&(x, y)| grid[(x, y)].is_ascii_digit());
+
+            let mut part_number_coordinates = Vec::with_capacity(8);
+
+            let mut coordinates = (x, y.wrapping_sub(1));
+            if neighbors.contains(&coordinates) {
+                part_number_coordinates.push(coordinates);
+            } else {
+                coordinates = (x.wrapping_sub(1), y.wrapping_sub(1));
+                if neighbors.contains(&coordinates) {
+                    part_number_coordinates.push(coordinates);
+                }
+
+                coordinates = (x + 1, y.wrapping_sub(1));
+                if neighbors.contains(&coordinates) {
+                    part_number_coordinates.push(coordinates);
+                }
+            }
+
+            coordinates = (x, y + 1);
+            if neighbors.contains(&coordinates) {
+                part_number_coordinates.push(coordinates);
+            } else {
+                coordinates = (x.wrapping_sub(1), y + 1);
+                if neighbors.contains(&coordinates) {
+                    part_number_coordinates.push(coordinates);
+                }
+
+                coordinates = (x + 1, y + 1);
+                if neighbors.contains(&coordinates) {
+                    part_number_coordinates.push(coordinates);
+                }
+            }
+
+            coordinates = (x.wrapping_sub(1), y);
+            if neighbors.contains(&coordinates) {
+                part_number_coordinates.push(coordinates);
+            }
+            coordinates = (x + 1, y);
+            if neighbors.contains(&coordinates) {
+                part_number_coordinates.push(coordinates);
+            }
+
+            if part_number_coordinates.len() != 2 {
+                return 0;
+            }
+
+            let gear_ratio: u64 = part_number_coordinates
+                .into_iter()
+                .map(|(mut x, y)| {
+                    let mut part_number = String::from(grid[(x, y)]);
+
+                    let start_x = x;
+
+                    while x > 0 && grid[(x - 1, y)].is_ascii_digit() {
+                        let char = grid[(x - 1, y)];
+                        part_number = format!("{char}{part_number}");
+
+                        x -= 1;
+                    }
+
+                    x = start_x;
+
+                    while x < grid.width() - 1 && grid[(x + 1, y)].is_ascii_digit() {
+                        let char = grid[(x + 1, y)];
+                        part_number.push(char);
+
+                        x += 1;
+                    }
+
+                    part_number
+                        .parse::<u64>()
+                        .expect("We only constructed `part_number` from ascii digits.")
+                })
+                .product();
+
+            gear_ratio
+        })
+        .sum();
+
+    Ok(gear_ratio_sum)
+}
+
+/// Every position touching the `length` cells starting at `(x, y)` and running right, diagonals
+/// included.
+fn span_neighbors(
+    grid: &Grid<char>,
+    x: usize,
+    y: usize,
+    length: usize,
+) -> BTreeSet<(usize, usize)> {
+    let span = x..(x + length);
+
+    span.clone()
+        .flat_map(|x| grid.neighbors8(x, y))
+        .map(|(position, _)| position)
+        .filter(|&(neighbor_x, neighbor_y)| !(neighbor_y == y && span.contains(&neighbor_x)))
+        .collect()
+}
@@ -0,0 +1,119 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{parse::Span, Answer, AocError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Scratchcard>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Span::new(input)
+            .lines()
+            .map(Scratchcard::try_from)
+            .collect()
+    }
+
+    fn part1(scratchcards: &Self::Input) -> Result<Answer, AocError> {
+        part1(scratchcards).map(Answer::from)
+    }
+
+    fn part2(scratchcards: &Self::Input) -> Result<Answer, AocError> {
+        part2(scratchcards).map(Answer::from)
+    }
+}
+
+pub struct Scratchcard {
+    id: u8,
+    player_numbers: HashSet<u8>,
+    winning_numbers: HashSet<u8>,
+}
+
+impl TryFrom<Span<'_>> for Scratchcard {
+    type Error = AocError;
+
+    fn try_from(line: Span<'_>) -> Result<Self, Self::Error> {
+        let (card_id, scratchcard) = line.key_value(':')?;
+        let id = card_id.strip_prefix("Card")?.parse()?;
+
+        let (winning_numbers, player_numbers) = scratchcard.split_once('|')?;
+
+        Ok(Self {
+            id,
+            player_numbers: player_numbers.ints()?.into_iter().collect(),
+            winning_numbers: winning_numbers.ints()?.into_iter().collect(),
+        })
+    }
+}
+
+impl FromStr for Scratchcard {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Span::new(s).try_into()
+    }
+}
+
+impl Eq for Scratchcard {}
+
+impl Ord for Scratchcard {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl PartialEq for Scratchcard {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl PartialOrd for Scratchcard {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Scratchcard {
+    fn score(&self) -> u64 {
+        let winning_number_count = self.winning_number_count() as u32;
+
+        if winning_number_count == 0 {
+            0
+        } else {
+            2_u64.pow(winning_number_count - 1)
+        }
+    }
+
+    fn winning_number_count(&self) -> usize {
+        self.player_numbers
+            .intersection(&self.winning_numbers)
+            .count()
+    }
+}
+
+fn part1(scratchcards: &[Scratchcard]) -> Result<u64, AocError> {
+    Ok(scratchcards.iter().map(Scratchcard::score).sum())
+}
+
+fn part2(scratchcards: &[Scratchcard]) -> Result<u64, AocError> {
+    let mut counts: Vec<u64> = vec![1; scratchcards.len()];
+
+    for (index, scratchcard) in scratchcards.iter().enumerate() {
+        let winning_number_count = scratchcard.winning_number_count();
+
+        let [scratchcard_count, next_scratchcard_counts @ ..] =
+            &mut counts[index..(index + 1 + winning_number_count)]
+        else {
+            unreachable!("Cards will never make you copy a card past the end of the table.");
+        };
+
+        next_scratchcard_counts
+            .iter_mut()
+            .for_each(|next_scratchcard_count| {
+                *next_scratchcard_count += *scratchcard_count;
+            });
+    }
+
+    Ok(counts.into_iter().sum())
+}
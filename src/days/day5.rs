@@ -0,0 +1,258 @@
+use std::{
+    ops::{ControlFlow, Range},
+    str::FromStr,
+};
+
+use crate::{cancel, parse::Span, Answer, AocError, Solution};
+
+/// How many locations part 2 tries between checks for whether it's been cancelled.
+const CANCEL_CHECK_INTERVAL: i64 = 1 << 12;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer, AocError> {
+        part1(almanac).map(Answer::from)
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer, AocError> {
+        part2(almanac).map(Answer::from)
+    }
+}
+
+#[derive(Default)]
+pub struct Almanac {
+    fertilizer_to_water: Vec<CategoryMap>,
+    humidity_to_location: Vec<CategoryMap>,
+    light_to_temperature: Vec<CategoryMap>,
+    seeds: Vec<i64>,
+    seed_ranges: Vec<Range<i64>>,
+    seed_to_soil: Vec<CategoryMap>,
+    soil_to_fertilizer: Vec<CategoryMap>,
+    temperature_to_humidity: Vec<CategoryMap>,
+    water_to_light: Vec<CategoryMap>,
+}
+
+struct CategoryMap {
+    offset: i64,
+    len: i64,
+    source_start: i64,
+}
+
+impl FromStr for Almanac {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut almanac = Self::default();
+
+        let input = Span::new(s);
+        let mut blocks = input.blocks();
+
+        let seeds_section = blocks
+            .next()
+            .ok_or_else(|| input.error("The almanac is empty."))?
+            .labelled("seeds")?;
+
+        let seeds: Vec<i64> = seeds_section.ints()?;
+
+        if !seeds.len().is_multiple_of(2) {
+            return Err(seeds_section.error("Expected the seeds to come in pairs."));
+        }
+
+        almanac.seed_ranges = seeds
+            .chunks_exact(2)
+            .map(|seed_range| {
+                let &[range_start, range_len] = seed_range else {
+                    unreachable!();
+                };
+
+                range_start..(range_start + range_len)
+            })
+            .collect();
+
+        almanac.seeds = seeds;
+
+        for block in blocks {
+            let (name, category_maps) = block.key_value(':')?;
+
+            let category_map_list = match name.as_str() {
+                "seed-to-soil map" => &mut almanac.seed_to_soil,
+                "soil-to-fertilizer map" => &mut almanac.soil_to_fertilizer,
+                "fertilizer-to-water map" => &mut almanac.fertilizer_to_water,
+                "water-to-light map" => &mut almanac.water_to_light,
+                "light-to-temperature map" => &mut almanac.light_to_temperature,
+                "temperature-to-humidity map" => &mut almanac.temperature_to_humidity,
+                "humidity-to-location map" => &mut almanac.humidity_to_location,
+                _ => return Err(name.error(format!("`{name}` is not a map."))),
+            };
+
+            for line in category_maps.lines() {
+                let [destination_range_start, source_range_start, range_length] = line.ints_n()?;
+
+                category_map_list.push(CategoryMap {
+                    offset: destination_range_start - source_range_start,
+                    len: range_length,
+                    source_start: source_range_start,
+                })
+            }
+        }
+
+        Ok(almanac)
+    }
+}
+
+impl Almanac {
+    fn location_to_seed(&self, location_value: i64) -> Option<i64> {
+        let humidity_value =
+            self.map_category_reverse(CategoryMappingReverse::LocationToHumidity, location_value);
+
+        let temperature_value = self.map_category_reverse(
+            CategoryMappingReverse::HumidityToTemperature,
+            humidity_value,
+        );
+
+        let light_value = self.map_category_reverse(
+            CategoryMappingReverse::TemperatureToLight,
+            temperature_value,
+        );
+
+        let water_value =
+            self.map_category_reverse(CategoryMappingReverse::LightToWater, light_value);
+
+        let fertilizer_value =
+            self.map_category_reverse(CategoryMappingReverse::WaterToFertilizer, water_value);
+
+        let soil_value =
+            self.map_category_reverse(CategoryMappingReverse::FertilizerToSoil, fertilizer_value);
+
+        let seed_value = self.map_category_reverse(CategoryMappingReverse::SoilToSeed, soil_value);
+
+        self.seed_ranges
+            .iter()
+            .any(|range| range.contains(&seed_value))
+            .then_some(seed_value)
+    }
+
+    fn map_category(&self, mapping: CategoryMapping, source_values: &mut [i64]) {
+        let category_map_list = match mapping {
+            CategoryMapping::FertilizerToWater => &self.fertilizer_to_water,
+            CategoryMapping::HumidityToLocation => &self.humidity_to_location,
+            CategoryMapping::LightToTemperature => &self.light_to_temperature,
+            CategoryMapping::SeedToSoil => &self.seed_to_soil,
+            CategoryMapping::SoilToFertilizer => &self.soil_to_fertilizer,
+            CategoryMapping::TemperatureToHumidity => &self.temperature_to_humidity,
+            CategoryMapping::WaterToLight => &self.water_to_light,
+        };
+
+        source_values.iter_mut().for_each(|source_value| {
+            let _ = category_map_list.iter().try_for_each(|category_map| {
+                if category_map.contains_source(*source_value) {
+                    *source_value += category_map.offset;
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+        });
+    }
+
+    fn map_category_reverse(&self, mapping: CategoryMappingReverse, source_value: i64) -> i64 {
+        let category_map_list = match mapping {
+            CategoryMappingReverse::FertilizerToSoil => &self.soil_to_fertilizer,
+            CategoryMappingReverse::HumidityToTemperature => &self.temperature_to_humidity,
+            CategoryMappingReverse::LightToWater => &self.water_to_light,
+            CategoryMappingReverse::LocationToHumidity => &self.humidity_to_location,
+            CategoryMappingReverse::SoilToSeed => &self.seed_to_soil,
+            CategoryMappingReverse::TemperatureToLight => &self.light_to_temperature,
+            CategoryMappingReverse::WaterToFertilizer => &self.fertilizer_to_water,
+        };
+
+        let mapped_value = category_map_list.iter().find_map(|category_map| {
+            category_map
+                .contains_dest(source_value)
+                .then_some(source_value - category_map.offset)
+        });
+
+        mapped_value.unwrap_or(source_value)
+    }
+
+    fn seeds_to_locations(&self) -> Vec<i64> {
+        let mut source_values = self.seeds.clone();
+
+        self.map_category(CategoryMapping::SeedToSoil, &mut source_values);
+        self.map_category(CategoryMapping::SoilToFertilizer, &mut source_values);
+        self.map_category(CategoryMapping::FertilizerToWater, &mut source_values);
+        self.map_category(CategoryMapping::WaterToLight, &mut source_values);
+        self.map_category(CategoryMapping::LightToTemperature, &mut source_values);
+        self.map_category(CategoryMapping::TemperatureToHumidity, &mut source_values);
+        self.map_category(CategoryMapping::HumidityToLocation, &mut source_values);
+
+        source_values
+    }
+}
+
+impl CategoryMap {
+    fn contains_dest(&self, source_value: i64) -> bool {
+        let range = (self.source_start + self.offset)..(self.source_start + self.offset + self.len);
+
+        range.contains(&source_value)
+    }
+
+    fn contains_source(&self, source_value: i64) -> bool {
+        let range = self.source_start..(self.source_start + self.len);
+
+        range.contains(&source_value)
+    }
+}
+
+enum CategoryMapping {
+    FertilizerToWater,
+    HumidityToLocation,
+    LightToTemperature,
+    SeedToSoil,
+    SoilToFertilizer,
+    TemperatureToHumidity,
+    WaterToLight,
+}
+
+enum CategoryMappingReverse {
+    FertilizerToSoil,
+    HumidityToTemperature,
+    LightToWater,
+    LocationToHumidity,
+    SoilToSeed,
+    TemperatureToLight,
+    WaterToFertilizer,
+}
+
+fn part1(almanac: &Almanac) -> Result<u64, AocError> {
+    let locations_for_seeds = almanac.seeds_to_locations();
+
+    locations_for_seeds
+        .into_iter()
+        .min()
+        .map(|min_location| min_location as u64)
+        .ok_or_else(|| AocError::solve("The almanac didn't list any seeds."))
+}
+
+fn part2(almanac: &Almanac) -> Result<u64, AocError> {
+    for location_value in 0.. {
+        if location_value % CANCEL_CHECK_INTERVAL == 0 {
+            cancel::check()?;
+        }
+
+        if almanac.location_to_seed(location_value).is_some() {
+            return Ok(location_value as u64);
+        }
+    }
+
+    Err(AocError::solve(
+        "No location maps back to one of the seeds.",
+    ))
+}
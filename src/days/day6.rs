@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use crate::{parse::Span, Answer, AocError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The sheet read both ways: as several short races, and as one long race with the spaces
+    /// between numbers ignored.
+    type Input = (BoatRaces, BoatRace);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok((input.parse()?, input.parse()?))
+    }
+
+    fn part1((boat_races, _): &Self::Input) -> Result<Answer, AocError> {
+        part1(boat_races).map(Answer::from)
+    }
+
+    fn part2((_, race): &Self::Input) -> Result<Answer, AocError> {
+        part2(race).map(Answer::from)
+    }
+}
+
+pub struct BoatRaces {
+    distances: Vec<u64>,
+    times: Vec<u64>,
+}
+
+impl FromStr for BoatRaces {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (times, distances) = race_descriptors(s)?;
+
+        Ok(Self {
+            times: times.ints()?,
+            distances: distances.ints()?,
+        })
+    }
+}
+
+impl BoatRaces {
+    fn iter(&self) -> BoatRacesIter<'_> {
+        BoatRacesIter {
+            boat_races: self,
+            current_index: 0,
+        }
+    }
+}
+
+struct BoatRacesIter<'a> {
+    boat_races: &'a BoatRaces,
+    current_index: usize,
+}
+
+impl Iterator for BoatRacesIter<'_> {
+    type Item = BoatRace;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let boat_race = self
+            .boat_races
+            .distances
+            .get(self.current_index)
+            .zip(self.boat_races.times.get(self.current_index))
+            .map(|(&distance, &time)| BoatRace { distance, time });
+
+        self.current_index += 1;
+
+        boat_race
+    }
+}
+
+pub struct BoatRace {
+    distance: u64,
+    time: u64,
+}
+
+impl FromStr for BoatRace {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (time, distance) = race_descriptors(s)?;
+
+        let digits = |values: Span<'_>| -> Result<u64, AocError> {
+            values.as_str().replace(' ', "").parse().map_err(|_| {
+                values.error(format!(
+                    "`{values}` is not a number, even without its spaces."
+                ))
+            })
+        };
+
+        Ok(Self {
+            time: digits(time)?,
+            distance: digits(distance)?,
+        })
+    }
+}
+
+/// The values from the `Time:` and `Distance:` lines of the sheet.
+fn race_descriptors(s: &str) -> Result<(Span<'_>, Span<'_>), AocError> {
+    let input = Span::new(s);
+    let mut lines = input.lines();
+
+    let mut next_line = |label| {
+        lines
+            .next()
+            .ok_or_else(|| input.error(format!("The sheet is missing its `{label}:` line.")))?
+            .labelled(label)
+    };
+
+    Ok((next_line("Time")?, next_line("Distance")?))
+}
+
+impl BoatRace {
+    fn first_winning_time(&self) -> u64 {
+        (1..self.time)
+            .find(|&button_holding_time| {
+                button_holding_time * (self.time - button_holding_time) > self.distance
+            })
+            .expect("A race record should always be beatable.")
+    }
+}
+
+fn part1(boat_races: &BoatRaces) -> Result<u64, AocError> {
+    Ok(boat_races
+        .iter()
+        .map(|race| (race.time + 1) - 2 * race.first_winning_time())
+        .product())
+}
+
+fn part2(race: &BoatRace) -> Result<u64, AocError> {
+    Ok((race.time + 1) - 2 * race.first_winning_time())
+}
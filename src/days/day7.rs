@@ -0,0 +1,190 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{parse::Span, Answer, AocError, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Span::new(input).lines().map(Hand::try_from).collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Result<Answer, AocError> {
+        total_winnings(hands.clone()).map(Answer::from)
+    }
+
+    fn part2(hands: &Self::Input) -> Result<Answer, AocError> {
+        total_winnings(hands.iter().map(Hand::with_jokers).collect()).map(Answer::from)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Hand {
+    hand_type: HandType,
+    cards: [Card; 5],
+    bid: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+enum Card {
+    Joker,
+    Two, // m Fwenwick
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack, // COOPER
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<Span<'_>> for Hand {
+    type Error = AocError;
+
+    fn try_from(line: Span<'_>) -> Result<Self, Self::Error> {
+        let (cards, bid) = line.split_once(' ')?;
+
+        let cards: Vec<Card> = cards
+            .as_str()
+            .chars()
+            .enumerate()
+            .map(|(index, char)| {
+                Card::try_from(char)
+                    .map_err(|()| cards.error_at(index, format!("`{char}` is not a card.")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let cards: [Card; 5] = cards.try_into().map_err(|cards: Vec<Card>| {
+            line.error(format!("Expected 5 cards, found {}.", cards.len()))
+        })?;
+
+        Ok(Hand::new(cards, bid.parse()?))
+    }
+}
+
+impl FromStr for Hand {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Span::new(s).try_into()
+    }
+}
+
+impl Hand {
+    fn new(cards: [Card; 5], bid: u64) -> Self {
+        let card_counts = cards.iter().copied().fold(
+            HashMap::new(),
+            |mut card_counts: HashMap<Card, u8>, card| {
+                *card_counts.entry(card).or_default() += 1;
+
+                card_counts
+            },
+        );
+
+        let hand_type = match card_counts.values().copied().max().expect(
+            "Card counts was constructed from a 5 element array, it will always have some entries.",
+        ) {
+            1 => HandType::HighCard,
+            2 => {
+                if card_counts.values().filter(|&&count| count == 2).count() == 2 {
+                    HandType::TwoPair
+                } else {
+                    HandType::Pair
+                }
+            }
+            3 => {
+                if card_counts.values().any(|&count| count == 2) {
+                    HandType::FullHouse
+                } else {
+                    HandType::ThreeOfAKind
+                }
+            }
+            4 => HandType::FourOfAKind,
+            5 => HandType::FiveOfAKind,
+            _ => unreachable!(),
+        };
+
+        let joker_count = card_counts.get(&Card::Joker).copied().unwrap_or_default();
+
+        let wildcard_hand_type = match (hand_type, joker_count) {
+            (_, 0) | (HandType::FiveOfAKind, _) => hand_type,
+            (HandType::HighCard, 1) => HandType::Pair,
+            (HandType::Pair, _) => HandType::ThreeOfAKind,
+            (HandType::TwoPair, 1) => HandType::FullHouse,
+            (HandType::TwoPair, 2) => HandType::FourOfAKind,
+            (HandType::ThreeOfAKind, _) => HandType::FourOfAKind,
+            (HandType::FullHouse, _) | (HandType::FourOfAKind, _) => HandType::FiveOfAKind,
+            _ => unreachable!(),
+        };
+
+        Hand {
+            hand_type: wildcard_hand_type,
+            cards,
+            bid,
+        }
+    }
+
+    /// The same hand, but with every Jack read as a Joker.
+    fn with_jokers(&self) -> Self {
+        let cards = self.cards.map(|card| match card {
+            Card::Jack => Card::Joker,
+            card => card,
+        });
+
+        Self::new(cards, self.bid)
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::Ten),
+            'J' => Ok(Self::Jack),
+            'Q' => Ok(Self::Queen),
+            'K' => Ok(Self::King),
+            'A' => Ok(Self::Ace),
+            _ => Err(()),
+        }
+    }
+}
+
+fn total_winnings(mut hands: Vec<Hand>) -> Result<u64, AocError> {
+    hands.sort_unstable();
+
+    Ok(hands
+        .into_iter()
+        .enumerate()
+        .fold(0_u64, |total_winnings, (index, Hand { bid, .. })| {
+            let rank = index as u64 + 1;
+
+            total_winnings + bid * rank
+        }))
+}
@@ -0,0 +1,239 @@
+use std::{collections::HashMap, iter, ops::ControlFlow, str::FromStr};
+
+use crate::{cancel, math, parse::Span, Answer, AocError, Solution};
+
+/// How many steps either part takes between checks for whether it's been cancelled.
+const CANCEL_CHECK_INTERVAL: u64 = 1 << 12;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, AocError> {
+        part1(map).map(Answer::from)
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, AocError> {
+        part2(map).map(Answer::from)
+    }
+}
+
+pub struct Map {
+    instructions: Vec<Instruction>,
+    network: HashMap<Node, (Node, Node)>,
+}
+
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Node(String);
+
+impl FromStr for Map {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = Span::new(s);
+        let mut blocks = input.blocks();
+
+        let instructions = blocks
+            .next()
+            .ok_or_else(|| input.error("The instruction line was not provided in the input."))?;
+
+        let instructions = instructions
+            .as_str()
+            .chars()
+            .enumerate()
+            .map(|(index, char)| {
+                Instruction::try_from(char).map_err(|()| {
+                    instructions.error_at(index, format!("`{char}` is not an instruction."))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let network = blocks
+            .flat_map(Span::lines)
+            .map(|line| {
+                let (node, neighbors) = line.key_value('=')?;
+                let (left, right) = neighbors
+                    .strip_prefix("(")?
+                    .strip_suffix(")")?
+                    .key_value(',')?;
+
+                Ok((node.into(), (left.into(), right.into())))
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(Self {
+            instructions,
+            network,
+        })
+    }
+}
+
+impl From<Span<'_>> for Node {
+    fn from(identifier: Span<'_>) -> Self {
+        Self(identifier.as_str().to_owned())
+    }
+}
+
+#[derive(Default)]
+struct Cycle {
+    start_index: Option<u64>,
+    end_index: Option<u64>,
+}
+
+impl Map {
+    fn cycles(&self) -> Result<impl Iterator<Item = Cycle> + '_, AocError> {
+        let mut current_nodes: Vec<&Node> = self
+            .network
+            .keys()
+            .filter(|Node(identifier)| identifier.ends_with('A'))
+            .collect();
+
+        let mut ending_nodes: HashMap<&Node, Cycle> = self
+            .network
+            .keys()
+            .filter(|Node(identifier)| identifier.ends_with('Z'))
+            .zip(iter::repeat_with(Cycle::default))
+            .collect();
+
+        let instruction_len = self.instructions.len() as u64;
+
+        let ControlFlow::Break(finished) = (1_u64..)
+            .zip(self.instructions.iter().cycle())
+            .try_for_each(|(index, instruction)| {
+                if index % CANCEL_CHECK_INTERVAL == 0 {
+                    if let Err(error) = cancel::check() {
+                        return ControlFlow::Break(Err(error));
+                    }
+                }
+
+                current_nodes.iter_mut().for_each(|node_ref| {
+                    let (left, right) = &self.network[node_ref];
+
+                    *node_ref = match instruction {
+                        Instruction::Left => left,
+                        Instruction::Right => right,
+                    };
+
+                    if let Some(cycle) = ending_nodes.get_mut(*node_ref) {
+                        if let Some(start_index) = cycle.start_index {
+                            if cycle.end_index.is_none()
+                                && (index % instruction_len == start_index % instruction_len)
+                            {
+                                cycle.end_index = Some(index);
+                            }
+                        } else {
+                            cycle.start_index = Some(index);
+                        }
+                    }
+                });
+
+                if ending_nodes
+                    .values()
+                    .all(|Cycle { end_index, .. }| end_index.is_some())
+                {
+                    ControlFlow::Break(Ok(()))
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+        else {
+            unreachable!("A cycling iterator will never finish without breaking.")
+        };
+
+        finished?;
+
+        Ok(ending_nodes.into_values())
+    }
+
+    fn steps_to_end(&self) -> Result<u64, AocError> {
+        if !self.network.contains_key(&Node(String::from("AAA"))) {
+            return Err(AocError::solve("The map has no `AAA` node to start from."));
+        }
+
+        let start = Node(String::from("AAA"));
+        let end = Node(String::from("ZZZ"));
+
+        let ControlFlow::Break(step_count) = self.instructions.iter().cycle().try_fold(
+            (0, &start),
+            |(step_count, current_node), instruction| {
+                if current_node == &end {
+                    return ControlFlow::Break(Ok(step_count));
+                }
+
+                // `ZZZ` may never be reached, so this needs to be able to give up.
+                if step_count % CANCEL_CHECK_INTERVAL == 0 {
+                    if let Err(error) = cancel::check() {
+                        return ControlFlow::Break(Err(error));
+                    }
+                }
+
+                let (left, right) = &self.network[current_node];
+
+                ControlFlow::Continue(match instruction {
+                    Instruction::Left => (step_count + 1, left),
+                    Instruction::Right => (step_count + 1, right),
+                })
+            },
+        ) else {
+            unreachable!("A cycling iterator will never finish without breaking.")
+        };
+
+        step_count
+    }
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+fn part1(map: &Map) -> Result<u64, AocError> {
+    map.steps_to_end()
+}
+
+fn part2(map: &Map) -> Result<u64, AocError> {
+    let start_node_count = map
+        .network
+        .keys()
+        .filter(|Node(identifier)| identifier.ends_with('A'))
+        .count();
+
+    let cycles: Vec<Cycle> = map.cycles()?.collect();
+    cycles.iter().for_each(|cycle| {
+        let Some((start_index, end_index)) = cycle.start_index.zip(cycle.end_index) else {
+            unreachable!();
+        };
+
+        assert_eq!(end_index % start_index, 0);
+    });
+
+    let start_indices: Vec<u64> = cycles
+        .iter()
+        .filter_map(|cycle| cycle.start_index)
+        .collect();
+    assert_eq!(start_indices.len(), start_node_count);
+
+    if start_indices.is_empty() {
+        return Err(AocError::solve("The map has no starting nodes."));
+    }
+
+    math::lcm_all(&start_indices)
+        .ok_or_else(|| AocError::solve("The number of steps doesn't fit in a `u64`."))
+}
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use crate::{parse::Span, Answer, AocError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        construct_histories(input)
+    }
+
+    fn part1(histories: &Self::Input) -> Result<Answer, AocError> {
+        part1(histories).map(Answer::from)
+    }
+
+    fn part2(histories: &Self::Input) -> Result<Answer, AocError> {
+        part2(histories).map(Answer::from)
+    }
+}
+
+fn construct_histories(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    Span::new(input).lines().map(Span::ints).collect()
+}
+
+fn part1(histories: &[Vec<i32>]) -> Result<i64, AocError> {
+    let next_value_sum = histories
+        .iter()
+        .map(|history| {
+            let mut sequences: Vec<Vec<i32>> = vec![history.clone()];
+
+            loop {
+                let last = &sequences[sequences.len() - 1];
+
+                let next_sequence: Vec<i32> = last
+                    .iter()
+                    .enumerate()
+                    .map_while(|(index, &element)| {
+                        last.get(index + 1)
+                            .map(|&next_element| next_element - element)
+                    })
+                    .collect();
+
+                let finished = next_sequence.iter().all(|&element| element == 0);
+
+                sequences.push(next_sequence);
+
+                if finished {
+                    break;
+                }
+            }
+
+            for index in (0..sequences.len()).rev() {
+                let sequence = &sequences[index];
+                let sequence_last_element = sequence[sequence.len() - 1];
+
+                let Some(previous_sequence) = index
+                    .checked_sub(1)
+                    .map(|previous_index| &mut sequences[previous_index])
+                else {
+                    break;
+                };
+
+                let previous_sequence_last_element = previous_sequence[previous_sequence.len() - 1];
+
+                previous_sequence.push(sequence_last_element + previous_sequence_last_element);
+            }
+
+            sequences[0][history.len()]
+        })
+        .map(i64::from)
+        .sum();
+
+    Ok(next_value_sum)
+}
+
+fn part2(histories: &[Vec<i32>]) -> Result<i64, AocError> {
+    let previous_value_sum = histories
+        .iter()
+        .map(|history| {
+            let mut sequences: Vec<VecDeque<i32>> = vec![VecDeque::from(history.clone())];
+
+            loop {
+                let last = &sequences[sequences.len() - 1];
+
+                let next_sequence: VecDeque<i32> = last
+                    .iter()
+                    .enumerate()
+                    .map_while(|(index, &element)| {
+                        last.get(index + 1)
+                            .map(|&next_element| next_element - element)
+                    })
+                    .collect();
+
+                let finished = next_sequence.iter().all(|&element| element == 0);
+
+                sequences.push(next_sequence);
+
+                if finished {
+                    break;
+                }
+            }
+
+            for index in (0..sequences.len()).rev() {
+                let sequence = &sequences[index];
+                let sequence_first_element = sequence[0];
+
+                let Some(previous_sequence) = index
+                    .checked_sub(1)
+                    .map(|previous_index| &mut sequences[previous_index])
+                else {
+                    break;
+                };
+
+                let previous_sequence_first_element = previous_sequence[0];
+
+                previous_sequence
+                    .push_front(previous_sequence_first_element - sequence_first_element);
+            }
+
+            sequences[0][0]
+        })
+        .map(i64::from)
+        .sum();
+
+    Ok(previous_value_sum)
+}
@@ -0,0 +1,123 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+use crate::Part;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// An input file couldn't be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input wasn't in the shape the parser expected. `line` and `column` count from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The per-user config file is missing something, or isn't in the right shape.
+    Config { path: PathBuf, message: String },
+    /// A file that gets updated automatically, like the list of days, isn't in the expected shape.
+    Edit { path: PathBuf, message: String },
+    /// A request to the puzzle site, or whatever's standing in for it, didn't work out.
+    Http { url: String, message: String },
+    /// The input parsed fine, but the solver couldn't get an answer out of it.
+    Solve { message: String },
+    /// The solver gave up because the runner stopped waiting for it.
+    Cancelled,
+    /// The parser or solver panicked. `location` is where, if it could be found out.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    /// Any of the above, tagged with where in the run it happened. `part` is `None` when both
+    /// parts are affected, e.g. because the input never parsed.
+    Participant {
+        day: u8,
+        part: Option<Part>,
+        participant: String,
+        source: Box<AocError>,
+    },
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::Solve {
+            message: message.into(),
+        }
+    }
+
+    pub fn for_participant(self, day: u8, part: Option<Part>, participant: &str) -> Self {
+        Self::Participant {
+            day,
+            part,
+            participant: participant.to_owned(),
+            source: Box::new(self),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "couldn't read {}: {source}", path.display()),
+            Self::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            Self::Config { path, message } => {
+                write!(f, "bad config in {}: {message}", path.display())
+            }
+            Self::Edit { path, message } => {
+                write!(f, "couldn't update {}: {message}", path.display())
+            }
+            Self::Http { url, message } => write!(f, "request to {url} failed: {message}"),
+            Self::Solve { message } => write!(f, "solver failed: {message}"),
+            Self::Cancelled => write!(f, "cancelled"),
+            Self::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {location}: {message}"),
+            Self::Panicked {
+                message,
+                location: None,
+            } => write!(f, "panicked: {message}"),
+            Self::Participant {
+                day,
+                part,
+                participant,
+                source,
+            } => {
+                write!(f, "day {day}")?;
+
+                if let Some(part) = part {
+                    write!(f, " part {part}")?;
+                }
+
+                write!(f, " ({}): {source}", participant.to_uppercase())
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Participant { source, .. } => Some(source.as_ref()),
+            Self::Parse { .. }
+            | Self::Config { .. }
+            | Self::Edit { .. }
+            | Self::Http { .. }
+            | Self::Solve { .. }
+            | Self::Cancelled
+            | Self::Panicked { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::ExpectedAnswers, runner::normalise, Answer, AocError, Part};
+
+/// An example input from a puzzle description, with the answers the text gives for it.
+#[derive(Debug)]
+pub struct Example {
+    /// What it's saved as in `input/dayN/`: `xmp` for an example both parts use, or `xmp.p1` and
+    /// `xmp.p2` when each part has its own.
+    pub name: String,
+    pub input: String,
+    pub answers: ExpectedAnswers,
+}
+
+/// Pulls the examples out of a puzzle page saved from the site. Each part's description is an
+/// `<article>`, whose first `<pre><code>` block is taken as its example, and whose last
+/// emphasised `<code>` as the example's answer, which is how the puzzles are written. A part that
+/// reuses part 1's example, or doesn't show one, has its answer added to that example instead.
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (article, part) in articles(page).into_iter().zip([Part::One, Part::Two]) {
+        let input = between(article, "<pre><code>", "</code></pre>")
+            .next()
+            .map(|block| normalise(&text(block)).0);
+        let answer = last_answer(article);
+
+        let example = match input {
+            Some(input) if !examples.iter().any(|example| example.input == input) => {
+                examples.push(Example {
+                    name: String::from("xmp"),
+                    input,
+                    answers: ExpectedAnswers::default(),
+                });
+
+                examples.last_mut()
+            }
+            Some(input) => examples.iter_mut().find(|example| example.input == input),
+            None => examples.last_mut(),
+        };
+
+        if let (Some(example), Some(answer)) = (example, answer) {
+            example.answers.set(part, answer);
+        }
+    }
+
+    if let [part1, part2] = &mut examples[..] {
+        part1.name = String::from("xmp.p1");
+        part2.name = String::from("xmp.p2");
+    }
+
+    examples
+}
+
+/// Saves `examples` in `day_dir` as `<name>.txt` and `<name>.answers`, returning the files it
+/// wrote. An example that's already there is left alone, and one that's different, or has
+/// different answers recorded, is an error rather than being overwritten.
+pub fn save(examples: &[Example], day_dir: &Path) -> Result<Vec<PathBuf>, AocError> {
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| AocError::Io { path, source }
+    };
+
+    fs::create_dir_all(day_dir).map_err(io_error(day_dir))?;
+
+    let mut written = Vec::new();
+
+    for example in examples {
+        let input_path = day_dir.join(format!("{}.txt", example.name));
+        let answers_path = day_dir.join(format!("{}.answers", example.name));
+
+        match fs::read_to_string(&input_path) {
+            Ok(existing) if normalise(&existing).0 == example.input => {}
+            Ok(_) => {
+                return Err(AocError::Edit {
+                    path: input_path,
+                    message: String::from(
+                        "There's a different example here already. Delete it to replace it.",
+                    ),
+                })
+            }
+            Err(_) => {
+                fs::write(&input_path, &example.input).map_err(io_error(&input_path))?;
+                written.push(input_path);
+            }
+        }
+
+        let mut answers = ExpectedAnswers::load_from(&answers_path)?;
+        let mut changed = false;
+
+        for part in [Part::One, Part::Two] {
+            match (answers.get(part), example.answers.get(part)) {
+                (Some(recorded), Some(answer)) if recorded != answer => {
+                    return Err(AocError::Edit {
+                        path: answers_path,
+                        message: format!(
+                            "Part {part}'s answer is recorded as {recorded}, but the puzzle \
+                             says {answer}."
+                        ),
+                    })
+                }
+                (None, Some(answer)) => {
+                    answers.set(part, answer.clone());
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+
+        if changed {
+            answers.save_to(&answers_path)?;
+            written.push(answers_path);
+        }
+    }
+
+    Ok(written)
+}
+
+/// Each part's `<article>`, or the whole page if it doesn't have any.
+fn articles(page: &str) -> Vec<&str> {
+    let articles: Vec<&str> = between(page, "<article", "</article>").collect();
+
+    if articles.is_empty() {
+        vec![page]
+    } else {
+        articles
+    }
+}
+
+/// The answer the description ends up at, in either of the ways the site emphasises code.
+fn last_answer(article: &str) -> Option<Answer> {
+    let last = |open, close| {
+        let (index, _) = article.rmatch_indices(open).next()?;
+
+        Some((index, between(&article[index..], open, close).next()?))
+    };
+
+    let (_, answer) = match (
+        last("<code><em>", "</em></code>"),
+        last("<em><code>", "</code></em>"),
+    ) {
+        (Some(a), Some(b)) => a.max(b),
+        (a, b) => a.or(b)?,
+    };
+    let Ok(answer) = text(answer).trim().parse();
+
+    Some(answer)
+}
+
+/// Everything between each `open` and the next `close` after it.
+fn between<'a>(s: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = s;
+
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let len = rest[start..].find(close)?;
+        let found = &rest[start..start + len];
+
+        rest = &rest[start + len + close.len()..];
+        Some(found)
+    })
+}
+
+/// The text of some HTML, without its tags and with its entities decoded.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(index) = rest.find(['<', '&']) {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((char, end)) => {
+                text.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+            Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?),
+            None => char::from_u32(entity.strip_prefix('#')?.parse().ok()?),
+        },
+    }
+}
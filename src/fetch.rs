@@ -0,0 +1,64 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{config::Config, http, AocError};
+
+/// Where a fetched input ended up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fetched {
+    /// It was already on disk, so the site wasn't asked for it again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure `initials` has an input for `day` at `<input_dir>/dayN/<initials>.txt`, downloading
+/// it with the session from `config` if it isn't there yet. An input that's already on disk is
+/// never downloaded again, as the site asks for inputs to be cached.
+pub fn fetch(
+    day: u8,
+    initials: &str,
+    config: &Config,
+    input_dir: &Path,
+) -> Result<Fetched, AocError> {
+    let path = input_dir
+        .join(format!("day{day}"))
+        .join(format!("{initials}.txt"));
+
+    if path.is_file() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = format!("{}/2023/day/{day}/input", config.base_url);
+    let cookie = format!("session={}", config.session()?);
+
+    let response = http::request("GET", &url, &[("Cookie", &cookie)], None)?;
+
+    if response.status != 200 {
+        return Err(AocError::Http {
+            url,
+            message: format!(
+                "{}: {}",
+                response.status,
+                response.body.trim().lines().next().unwrap_or_default()
+            ),
+        });
+    }
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| AocError::Io { path, source }
+    };
+
+    let day_dir = path.parent().unwrap_or(input_dir);
+    fs::create_dir_all(day_dir).map_err(io_error(day_dir))?;
+
+    // Written to the side first, so an interrupted download can't leave half an input behind to be
+    // mistaken for a cached one.
+    let partial_path = path.with_extension("txt.partial");
+    fs::write(&partial_path, response.body).map_err(io_error(&partial_path))?;
+    fs::rename(&partial_path, &path).map_err(io_error(&path))?;
+
+    Ok(Fetched::Downloaded(path))
+}
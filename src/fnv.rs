@@ -0,0 +1,12 @@
+//! The 64-bit FNV-1a hash, which unlike `DefaultHasher` is the same from one build to the next.
+//! `build.rs` uses it too, to hash the solvers' sources.
+
+/// Where a hash starts, before it's seen any bytes.
+pub const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Carries on a hash from `hash` over `bytes`.
+pub fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
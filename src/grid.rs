@@ -0,0 +1,275 @@
+use std::{fmt, ops::Index, str::FromStr};
+
+use crate::AocError;
+
+/// A rectangular grid of cells, stored row by row. Positions are `(x, y)`, with `(0, 0)` at the top
+/// left, `x` counting columns and `y` counting rows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+
+/// The offsets of the 4 cells sharing an edge with a cell, clockwise from above.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the 8 cells sharing an edge or a corner with a cell, clockwise from above.
+const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    /// Parses one row per line, turning each `char` into a cell with `cell`. Every line must be as
+    /// long as the first, and there must be at least one.
+    pub fn parse_with<E: fmt::Display>(
+        s: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, AocError> {
+        let mut cells = Vec::with_capacity(s.len());
+        let mut width = None;
+
+        for (index, line) in s.lines().enumerate() {
+            let row_start = cells.len();
+
+            for (column, char) in line.chars().enumerate() {
+                cells.push(
+                    cell(char).map_err(|error| {
+                        AocError::parse(index + 1, column + 1, error.to_string())
+                    })?,
+                );
+            }
+
+            let row_width = cells.len() - row_start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::parse(
+                        index + 1,
+                        row_width.min(width) + 1,
+                        format!("Expected every row to be {width} cells wide."),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { cells, width }),
+            _ => Err(AocError::parse(1, 1, "The grid is empty.")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    /// The cell at `(x, y)`, or `None` if that's off the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.cells.get(self.index_of(x, y)?)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        let index = self.index_of(x, y)?;
+
+        self.cells.get_mut(index)
+    }
+
+    /// Where `(x, y)` is in `cells`, checked first so that no position can wrap around onto the
+    /// grid.
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height()).then(|| y * self.width + x)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| ((index % self.width, index / self.width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.cells.chunks_exact(self.width).nth(y)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T> + '_> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// The cells up, right, down and left of `(x, y)`, leaving out any that are off the grid.
+    pub fn neighbors4(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// The cells around `(x, y)`, diagonals included, leaving out any that are off the grid.
+    pub fn neighbors8(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.offsets(x, y, &SURROUNDING)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+
+            Some(((x, y), self.get(x, y)?))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is off the {}x{} grid.",
+                self.width,
+                self.height()
+            )
+        })
+    }
+}
+
+/// Parses any grid whose cells can be made from a `char`, e.g. `Grid<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, T::try_from)
+    }
+}
+
+/// Prints one row per line, with no separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "ab\ncd\n".parse().unwrap()
+    }
+
+    #[test]
+    fn gets_cells_only_on_the_grid() {
+        let mut grid = grid();
+
+        assert_eq!(grid.get(1, 0), Some(&'b'));
+        assert_eq!(grid.get(0, 1), Some(&'c'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.get(0, usize::MAX / 2 + 1), None);
+        assert_eq!(grid.get(usize::MAX, usize::MAX), None);
+
+        *grid.get_mut(1, 1).unwrap() = 'x';
+        assert_eq!(grid[(1, 1)], 'x');
+        assert_eq!(grid.get_mut(0, usize::MAX / 2 + 1), None);
+    }
+
+    #[test]
+    fn leaves_out_neighbours_off_the_grid() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            [((1, 0), &'b'), ((0, 1), &'c')]
+        );
+        assert_eq!(
+            grid.neighbors8(0, 0).collect::<Vec<_>>(),
+            [((1, 0), &'b'), ((1, 1), &'d'), ((0, 1), &'c')]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 3);
+    }
+
+    #[test]
+    fn reads_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.row(1), Some(&['c', 'd'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).unwrap().collect::<String>(), "bd");
+        assert!(grid.column(2).is_none());
+        assert_eq!(grid.iter().nth(2), Some(((0, 1), &'c')));
+        assert_eq!(grid.to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn reports_where_a_grid_goes_wrong() {
+        assert!(matches!(
+            "abc\nde\n".parse::<Grid<char>>(),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            "".parse::<Grid<char>>(),
+            Err(AocError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::parse_with("01\n2x\n", |char| char.to_digit(10).ok_or("Not a digit.")),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+}
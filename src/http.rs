@@ -0,0 +1,205 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use crate::AocError;
+
+/// Sent with every request, as the Advent of Code site asks automated tools to say who they are.
+const USER_AGENT: &str = "aoc2023 (https://github.com/jack-cooper/aoc2023)";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+/// Makes a request with the given extra headers, and a form body if there is one. Plain `http://`
+/// URLs are handled here, which is all a local stand-in server needs. There's no TLS without
+/// dependencies, so `https://` URLs are handed to `curl` instead.
+pub(crate) fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, AocError> {
+    let error = |message: String| AocError::Http {
+        url: url.to_owned(),
+        message,
+    };
+
+    if let Some(rest) = url.strip_prefix("http://") {
+        request_plain(method, rest, headers, body).map_err(error)
+    } else if url.starts_with("https://") {
+        request_curl(method, url, headers, body).map_err(error)
+    } else {
+        Err(error(String::from(
+            "Only http:// and https:// URLs are supported.",
+        )))
+    }
+}
+
+fn request_plain(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let (authority, path) = match url.find('/') {
+        Some(index) => url.split_at(index),
+        None => (url, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_owned()
+    } else {
+        format!("{authority}:80")
+    };
+
+    let mut stream = TcpStream::connect(&address).map_err(|error| error.to_string())?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .map_err(|error| error.to_string())?;
+
+    let mut request = format!(
+        "{method} {path} HTTP/1.1\r\nHost: {authority}\r\nUser-Agent: {USER_AGENT}\r\nConnection: close\r\n"
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|error| error.to_string())?;
+
+    let mut reader = BufReader::new(stream);
+    let mut status_line = String::new();
+    reader
+        .read_line(&mut status_line)
+        .map_err(|error| error.to_string())?;
+
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| format!("`{}` is not an HTTP status line.", status_line.trim()))?;
+
+    let mut chunked = false;
+    loop {
+        let mut header = String::new();
+        reader
+            .read_line(&mut header)
+            .map_err(|error| error.to_string())?;
+
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            chunked |= name.eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked");
+        }
+    }
+
+    let mut body = Vec::new();
+    if chunked {
+        loop {
+            let mut size = String::new();
+            reader
+                .read_line(&mut size)
+                .map_err(|error| error.to_string())?;
+
+            let size = usize::from_str_radix(size.trim().split(';').next().unwrap_or_default(), 16)
+                .map_err(|_| format!("`{}` is not a chunk size.", size.trim()))?;
+            if size == 0 {
+                break;
+            }
+
+            let mut chunk = vec![0; size + 2];
+            reader
+                .read_exact(&mut chunk)
+                .map_err(|error| error.to_string())?;
+            body.extend_from_slice(&chunk[..size]);
+        }
+    } else {
+        reader
+            .read_to_end(&mut body)
+            .map_err(|error| error.to_string())?;
+    }
+
+    let body = String::from_utf8(body).map_err(|_| String::from("The response wasn't UTF-8."))?;
+
+    Ok(Response { status, body })
+}
+
+fn request_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--request", method])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        // The status code goes on a line of its own after the body, to be split off below.
+        .args(["--write-out", "\n%{http_code}"]);
+
+    // Headers go through stdin, one per line, as anyone on the machine can see a command line, and
+    // the session cookie is one of them.
+    let headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}\n"))
+        .collect();
+    command.args(["--header", "@-"]);
+
+    if let Some(body) = body {
+        command.args(["--data", body]);
+    }
+
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Couldn't run curl, which https:// URLs need: {error}"))?;
+
+    // Taking stdin closes it once it's written, so curl knows there are no more headers.
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(headers.as_bytes())
+            .map_err(|error| format!("Couldn't give curl the headers: {error}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("curl didn't finish: {error}"))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+    }
+
+    let output =
+        String::from_utf8(output.stdout).map_err(|_| String::from("The response wasn't UTF-8."))?;
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or_else(|| String::from("curl didn't report a status code."))?;
+
+    Ok(Response {
+        status: status
+            .parse()
+            .map_err(|_| format!("`{status}` is not an HTTP status code."))?,
+        body: body.to_owned(),
+    })
+}
@@ -0,0 +1,159 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::AocError;
+
+thread_local! {
+    /// Whether this thread is inside [`catch_panic`], so the hook should keep quiet.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic caught on this thread.
+    static LAST_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Wraps the existing panic hook so that panics inside [`catch_panic`] are recorded for the
+/// runner to report, rather than printed. Panics anywhere else still go to the existing hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let message = payload_message(info.payload());
+                let location = info.location().map(ToString::to_string);
+
+                LAST_PANIC.with(|last_panic| *last_panic.borrow_mut() = Some((message, location)));
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("(no message)")
+    }
+}
+
+/// Runs `f`, turning a panic into an [`AocError::Panicked`] instead of letting it unwind any
+/// further.
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, AocError> {
+    install_hook();
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    result.map_err(|payload| {
+        let (message, location) = LAST_PANIC
+            .with(|last_panic| last_panic.borrow_mut().take())
+            .unwrap_or_else(|| (payload_message(payload.as_ref()), None));
+
+        AocError::Panicked { message, location }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        runner::{self, Outcome, RunOptions},
+        Answer, Part, Solution,
+    };
+
+    /// Part 1 panics, and part 2 answers as normal. An input of `panic` panics while parsing.
+    struct Panicky;
+
+    impl Solution for Panicky {
+        type Input = ();
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            if input == "panic" {
+                panic!("The parser gave up.");
+            }
+
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<Answer, AocError> {
+            panic!("Part 1 gave up.");
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Answer, AocError> {
+            Ok(Answer::from(2))
+        }
+    }
+
+    fn panicked(outcome: &Outcome) -> Option<(&str, Option<&str>)> {
+        match outcome {
+            Outcome::Failed(error) => match error.as_ref() {
+                AocError::Participant { source, .. } => match source.as_ref() {
+                    AocError::Panicked { message, location } => {
+                        Some((message, location.as_deref()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn records_the_panic_message_and_where_it_happened() {
+        let Err(AocError::Panicked { message, location }) =
+            catch_panic(|| panic!("Something went {}.", "wrong"))
+        else {
+            panic!("Expected the panic to be caught.");
+        };
+
+        assert_eq!(message, "Something went wrong.");
+        assert!(location.is_some_and(|location| location.starts_with("src/isolate.rs:")));
+
+        assert_eq!(catch_panic(|| 42).unwrap(), 42);
+    }
+
+    #[test]
+    fn keeps_solving_the_other_part_after_one_panics() {
+        let results =
+            runner::solve_input::<Panicky>(1, &RunOptions::default(), "abc", Ok(String::new()));
+
+        let (message, location) =
+            panicked(&results[0].outcome).expect("Part 1 should have panicked.");
+        assert_eq!(results[0].part, Part::One);
+        assert_eq!(message, "Part 1 gave up.");
+        assert!(location.is_some());
+
+        assert_eq!(results[1].part, Part::Two);
+        assert!(
+            matches!(&results[1].outcome, Outcome::Solved(answer) if *answer == Answer::from(2))
+        );
+    }
+
+    #[test]
+    fn fails_both_parts_when_parsing_panics() {
+        let results = runner::solve_input::<Panicky>(
+            1,
+            &RunOptions::default(),
+            "abc",
+            Ok(String::from("panic")),
+        );
+
+        assert_eq!(results.len(), 2);
+
+        for result in &results {
+            let (message, _) = panicked(&result.outcome).expect("Parsing should have panicked.");
+            assert_eq!(message, "The parser gave up.");
+        }
+    }
+}
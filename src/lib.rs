@@ -1,35 +1,59 @@
-use std::fs;
-
-const INITIALS: [&str; 4] = ["xmp", "jwc", "scb", "tmf"];
-
-fn read_input_file(day: u8, initials: &str) -> String {
-    fs::read_to_string(format!("input/day{day}/{initials}.txt"))
-        .expect("You typo'd a filename (or sutin).")
+use std::fmt;
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use runner::solve_day;
+
+pub mod allocations;
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod config;
+pub mod days;
+mod error;
+pub mod examples;
+pub mod fetch;
+mod fnv;
+pub mod grid;
+mod http;
+mod isolate;
+pub mod math;
+pub mod parse;
+pub mod pool;
+#[cfg(test)]
+mod regression;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+/// A day's puzzle, split into a parse step shared by both parts and the two parts themselves.
+pub trait Solution {
+    /// The puzzle input, parsed into whatever shape both parts want to work with. It's shared with
+    /// the thread each part runs on when there's a time budget.
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
 }
 
-pub fn solve_day(day: u8, part1: fn(&str) -> Result<u64, ()>, part2: fn(&str) -> Result<u64, ()>) {
-    println!("===== Begin Part 1 =====");
-    let _ = INITIALS.iter().try_for_each(|&initials| {
-        let input = read_input_file(day, initials);
-
-        let answer = part1(&input)?;
-        println!("{}: {}", initials.to_uppercase(), answer);
-
-        Ok::<(), ()>(())
-    });
-    println!("=====  End Part 1  =====");
-
-    println!();
-
-    println!("===== Begin Part 2 =====");
-    let _ = INITIALS.iter().try_for_each(|&initials| {
-        let input = read_input_file(day, initials);
-
-        let answer = part2(&input)?;
-
-        println!("{}: {}", initials.to_uppercase(), answer);
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
 
-        Ok::<(), ()>(())
-    });
-    println!("=====  End Part 2  =====");
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
 }
@@ -0,0 +1,188 @@
+use crate::AocError;
+
+/// The greatest common divisor of `a` and `b`, by Euclid's algorithm. `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it doesn't fit in a `u64`. The LCM of 0
+/// and anything is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The GCD of every value, or `None` if there are none.
+pub fn gcd_all(values: &[u64]) -> Option<u64> {
+    values.iter().copied().reduce(gcd)
+}
+
+/// The LCM of every value, or `None` if there are none or it doesn't fit in a `u64`.
+pub fn lcm_all(values: &[u64]) -> Option<u64> {
+    let (&first, rest) = values.split_first()?;
+
+    rest.iter()
+        .try_fold(first, |lcm_so_far, &value| lcm(lcm_so_far, value))
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the non-negative GCD of `a` and
+/// `b`. `None` if any of them don't fit in an `i64`, which can only happen around `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+
+    Some((g.try_into().ok()?, x.try_into().ok()?, y.try_into().ok()?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `modulus` isn't positive or
+/// `a` and `modulus` share a factor, in which case there isn't one.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd_wide(a.into(), modulus.into());
+
+    (g == 1).then(|| x.rem_euclid(modulus.into()) as i64)
+}
+
+/// Solves a system of congruences `x ≡ remainder (mod modulus)`, given as `(remainder, modulus)`
+/// pairs, by the Chinese Remainder Theorem. The moduli needn't be coprime. Returns `(x, m)`, where
+/// `m` is the LCM of the moduli and `x` in `0..m` is the smallest non-negative solution, so every
+/// solution is `x + k * m`. No congruences at all gives `(0, 1)`.
+///
+/// Fails if a modulus isn't positive, the congruences contradict each other, or `m` doesn't fit in
+/// an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<(i64, i64), AocError> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, m), &(remainder, modulus)| {
+            if modulus <= 0 {
+                return Err(AocError::solve(format!(
+                    "{modulus} is not a valid modulus, it must be positive."
+                )));
+            }
+
+            let (x, m) = (i128::from(x), i128::from(m));
+            let (remainder, modulus) = (i128::from(remainder), i128::from(modulus));
+
+            // m * p + modulus * q == g, so x + m * p * (remainder - x) / g satisfies both.
+            let (g, p, _) = extended_gcd_wide(m, modulus);
+            let difference = remainder - x;
+
+            if difference % g != 0 {
+                return Err(AocError::solve(format!(
+                    "No number is {x} mod {m} and {remainder} mod {modulus}."
+                )));
+            }
+
+            let step = modulus / g;
+            let lcm = i64::try_from(m * step).map_err(|_| {
+                AocError::solve(format!("The LCM of the moduli overflows at {modulus}."))
+            })?;
+
+            // Both factors are reduced below `step` first, so their product fits in an `i128`.
+            let k = ((difference / g).rem_euclid(step) * p.rem_euclid(step)).rem_euclid(step);
+            let x = (x + m * k).rem_euclid(lcm.into());
+
+            Ok((x as i64, lcm))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 5), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(gcd_all(&[]), None);
+        assert_eq!(gcd_all(&[12, 18, 8]), Some(2));
+        assert_eq!(lcm_all(&[]), None);
+        assert_eq!(lcm_all(&[2, 3, 4]), Some(12));
+        assert_eq!(lcm_all(&[2, 3, u64::MAX]), None);
+    }
+
+    #[test]
+    fn extended_gcd_finds_bezout_coefficients() {
+        for (a, b) in [(240, 46), (-240, 46), (240, -46), (0, 5), (5, 0), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+
+        assert_eq!(extended_gcd(i64::MIN, 1), Some((1, 0, 1)));
+        // The GCD is 2^63, one more than an `i64` holds.
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), None);
+    }
+
+    #[test]
+    fn mod_inverse_only_exists_for_coprime_values() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -11), None);
+        // Everything is 0 mod 1, 1 included.
+        assert_eq!(mod_inverse(1, 1), Some(0));
+        // i64::MIN is -1 mod i64::MAX, which is its own inverse.
+        assert_eq!(mod_inverse(i64::MIN, i64::MAX), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    fn crt_solves_coprime_and_non_coprime_systems() {
+        assert_eq!(crt(&[]).unwrap(), (0, 1));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]).unwrap(), (23, 105));
+        assert_eq!(crt(&[(-1, 5)]).unwrap(), (4, 5));
+        assert_eq!(crt(&[(2, 4), (4, 6)]).unwrap(), (10, 12));
+        assert_eq!(crt(&[(3, 6), (3, 6)]).unwrap(), (3, 6));
+
+        // Big enough that the working would overflow an `i64`.
+        let (x, m) = crt(&[(5, 1_000_000_007), (7, 998_244_353)]).unwrap();
+        assert_eq!(m, 1_000_000_007 * 998_244_353);
+        assert_eq!((x % 1_000_000_007, x % 998_244_353), (5, 7));
+    }
+
+    #[test]
+    fn crt_fails_on_bad_systems() {
+        // Inconsistent: odd and even at once.
+        assert!(crt(&[(1, 4), (2, 6)]).is_err());
+        assert!(crt(&[(0, 0)]).is_err());
+        assert!(crt(&[(1, -3)]).is_err());
+        // The LCM of the moduli overflows an `i64`.
+        assert!(crt(&[(0, i64::MAX), (0, i64::MAX - 1)]).is_err());
+    }
+}
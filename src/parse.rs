@@ -0,0 +1,273 @@
+use std::{fmt, iter, str::FromStr};
+
+use crate::AocError;
+
+/// A piece of the input that remembers where it came from, so anything wrong with it can be
+/// reported at the right line and column. Every helper hands out spans of the same input, so
+/// positions stay right however far it's been picked apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span<'a> {
+    text: &'a str,
+    /// The whole input, which the line and column are only worked out from when they're needed,
+    /// as doing it for every span would make picking an input apart quadratic.
+    source: &'a str,
+    /// Where `text` starts in `source`, in bytes.
+    start: usize,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of an input, starting at line 1, column 1.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            source: text,
+            start: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Counting from 1, like [`AocError::Parse`].
+    pub fn line(&self) -> usize {
+        self.source[..self.start].matches('\n').count() + 1
+    }
+
+    /// Counting from 1, like [`AocError::Parse`].
+    pub fn column(&self) -> usize {
+        let before = &self.source[..self.start];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        before[line_start..].chars().count() + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// A parse error pointing at the start of this span.
+    pub fn error(&self, message: impl Into<String>) -> AocError {
+        AocError::parse(self.line(), self.column(), message)
+    }
+
+    /// A parse error pointing `offset` characters into this span.
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> AocError {
+        let start = self
+            .text
+            .char_indices()
+            .nth(offset)
+            .map_or(self.text.len(), |(index, _)| index);
+
+        self.within(&self.text[start..]).error(message)
+    }
+
+    /// The span of `part`, which must be a slice of this span's text.
+    fn within(&self, part: &'a str) -> Self {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        debug_assert!(offset + part.len() <= self.text.len());
+
+        Self {
+            text: part,
+            source: self.source,
+            start: self.start + offset,
+        }
+    }
+
+    pub fn trim(self) -> Self {
+        self.within(self.text.trim())
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = Self> + 'a {
+        self.text.lines().map(move |line| self.within(line))
+    }
+
+    /// The groups of lines separated by blank lines, leaving out the blank lines themselves.
+    pub fn blocks(self) -> impl Iterator<Item = Self> + 'a {
+        let mut lines = self.lines().peekable();
+
+        iter::from_fn(move || {
+            while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+
+            let first = lines.next()?;
+            let mut last = first;
+
+            while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+                last = line;
+            }
+
+            let start = first.text.as_ptr() as usize - self.text.as_ptr() as usize;
+            let end = last.text.as_ptr() as usize - self.text.as_ptr() as usize + last.text.len();
+
+            Some(self.within(&self.text[start..end]))
+        })
+    }
+
+    /// The whitespace-separated words.
+    pub fn words(self) -> impl Iterator<Item = Self> + 'a {
+        self.text
+            .split_whitespace()
+            .map(move |word| self.within(word))
+    }
+
+    /// The pieces between each `separator`, untrimmed.
+    pub fn split(self, separator: char) -> impl Iterator<Item = Self> + 'a {
+        self.text
+            .split(separator)
+            .map(move |piece| self.within(piece))
+    }
+
+    /// The parts before and after the first `separator`, untrimmed.
+    pub fn split_once(self, separator: char) -> Result<(Self, Self), AocError> {
+        let (before, after) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("Expected a `{separator}`.")))?;
+
+        Ok((self.within(before), self.within(after)))
+    }
+
+    /// Splits a `key <separator> value` line, trimming both sides.
+    pub fn key_value(self, separator: char) -> Result<(Self, Self), AocError> {
+        let (key, value) = self.split_once(separator)?;
+
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// The body of a `label: body` section, which may run on over any number of lines. Fails if
+    /// the section has any other label.
+    pub fn labelled(self, label: &str) -> Result<Self, AocError> {
+        let (found, body) = self.key_value(':')?;
+
+        if found.text == label {
+            Ok(body)
+        } else {
+            Err(found.error(format!("Expected `{label}:`.")))
+        }
+    }
+
+    pub fn strip_prefix(self, prefix: &str) -> Result<Self, AocError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.within(rest))
+            .ok_or_else(|| self.error(format!("Expected `{prefix}`.")))
+    }
+
+    pub fn strip_suffix(self, suffix: &str) -> Result<Self, AocError> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.within(rest))
+            .ok_or_else(|| {
+                self.error_at(self.text.chars().count(), format!("Expected `{suffix}`."))
+            })
+    }
+
+    /// Parses the whole span, ignoring whitespace around it.
+    pub fn parse<T>(self) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let trimmed = self.trim();
+
+        trimmed
+            .text
+            .parse()
+            .map_err(|error| trimmed.error(format!("Couldn't parse `{}`: {error}.", trimmed.text)))
+    }
+
+    /// Every whitespace-separated number, failing on the first word that isn't one.
+    pub fn ints<T>(self) -> Result<Vec<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.words().map(Self::parse).collect()
+    }
+
+    /// Exactly `N` whitespace-separated numbers.
+    pub fn ints_n<T, const N: usize>(self) -> Result<[T; N], AocError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.ints()?.try_into().map_err(|ints: Vec<T>| {
+            self.error(format!("Expected {N} numbers, found {}.", ints.len()))
+        })
+    }
+}
+
+impl fmt::Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(span: Span<'_>) -> (usize, usize, &str) {
+        (span.line(), span.column(), span.as_str())
+    }
+
+    fn error_position(error: AocError) -> (usize, usize) {
+        match error {
+            AocError::Parse { line, column, .. } => (line, column),
+            error => panic!("Expected a parse error, got {error}"),
+        }
+    }
+
+    #[test]
+    fn keeps_positions_through_lines_and_words() {
+        let input = Span::new("ab cd\n  ef\n\ngh");
+
+        let words: Vec<_> = input.lines().flat_map(Span::words).map(position).collect();
+
+        assert_eq!(
+            words,
+            [(1, 1, "ab"), (1, 4, "cd"), (2, 3, "ef"), (4, 1, "gh")]
+        );
+    }
+
+    #[test]
+    fn splits_into_blocks_separated_by_blank_lines() {
+        let blocks: Vec<_> = Span::new("\na\nb\n\n \nc\n")
+            .blocks()
+            .map(position)
+            .collect();
+
+        assert_eq!(blocks, [(2, 1, "a\nb"), (6, 1, "c")]);
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let (_, value) = Span::new("ü→: x").key_value(':').unwrap();
+
+        assert_eq!(position(value), (1, 5, "x"));
+        assert_eq!(
+            error_position(Span::new("ab\nü→x").lines().nth(1).unwrap().error_at(2, "")),
+            (2, 3)
+        );
+    }
+
+    #[test]
+    fn points_errors_at_the_problem() {
+        let line = Span::new("Card 1: 41 x 3\nGame 2: 1 2")
+            .lines()
+            .nth(1)
+            .unwrap();
+
+        assert_eq!(error_position(line.labelled("Card").unwrap_err()), (2, 1));
+        assert_eq!(error_position(line.strip_suffix(";").unwrap_err()), (2, 12));
+        assert_eq!(error_position(line.split_once('|').unwrap_err()), (2, 1));
+
+        let first = Span::new("Card 1: 41 x 3").labelled("Card 1").unwrap();
+        assert_eq!(error_position(first.ints::<u32>().unwrap_err()), (1, 12));
+        assert_eq!(
+            error_position(Span::new("  1 2").ints_n::<u32, 3>().unwrap_err()),
+            (1, 1)
+        );
+        assert_eq!(Span::new(" 1 2 3").ints_n::<u32, 3>().unwrap(), [1, 2, 3]);
+    }
+}
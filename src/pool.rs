@@ -0,0 +1,98 @@
+use std::{num::NonZeroUsize, sync::Mutex, thread};
+
+/// One worker per core, or a single worker if that can't be found out.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Maps `f` over `items` on up to `jobs` worker threads, each taking the next item as soon as it's
+/// free. Results come back in the same order as `items`, however the work was interleaved.
+pub fn map<T, R, F>(jobs: usize, items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let len = items.len();
+    let jobs = jobs.clamp(1, len.max(1));
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(len));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let next = queue.lock().expect("A worker panicked.").next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+
+                let result = f(item);
+                results
+                    .lock()
+                    .expect("A worker panicked.")
+                    .push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().expect("A worker panicked.");
+    results.sort_unstable_by_key(|&(index, _)| index);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{panic, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn keeps_results_in_the_order_of_the_items() {
+        // Earlier items take longer, so they finish last.
+        let items: Vec<u64> = (0..16).collect();
+        let results = map(4, items, |item| {
+            thread::sleep(Duration::from_millis(16 - item));
+            item * 10
+        });
+
+        assert_eq!(results, (0..16).map(|item| item * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn keeps_each_failure_with_its_item() {
+        let results = map(3, vec![1, 0, 2, 0], |item: u32| {
+            10u32.checked_div(item).ok_or(format!("{item} failed"))
+        });
+
+        assert_eq!(
+            results,
+            [
+                Ok(10),
+                Err(String::from("0 failed")),
+                Ok(5),
+                Err(String::from("0 failed"))
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_fewer_items_than_jobs() {
+        assert_eq!(map(8, vec![1], |item: i32| item + 1), [2]);
+        assert_eq!(map(8, Vec::new(), |item: i32| item + 1), []);
+        assert_eq!(map(0, vec![1, 2], |item: i32| item + 1), [2, 3]);
+    }
+
+    #[test]
+    fn passes_on_a_panic_in_a_worker() {
+        let result = panic::catch_unwind(|| {
+            map(2, vec![1, 2, 3], |item: i32| {
+                assert_ne!(item, 2, "Item 2 is not allowed.");
+                item
+            })
+        });
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Regression tests generated by `build.rs`, one per day, part and input file with a recorded
+//! answer in `input/dayN/<initials>.answers`.
+
+use std::{env, fs, path::Path};
+
+use crate::{
+    answers::ExpectedAnswers,
+    days,
+    runner::{Inputs, Outcome, RunOptions},
+    Part,
+};
+
+fn check(day: u8, part: Part, participant: &str) {
+    check_in(Path::new("input"), day, part, participant);
+}
+
+/// Checks the one input file `participant` names, and not any `.p1` or `.p2` inputs alongside it,
+/// which have answers of their own.
+fn check_in(input_dir: &Path, day: u8, part: Part, participant: &str) {
+    let expected = ExpectedAnswers::load_from(&ExpectedAnswers::path(input_dir, day, participant))
+        .unwrap_or_else(|error| panic!("Couldn't load the recorded answers: {error}"));
+    let expected = expected
+        .get(part)
+        .expect("Tests are only generated for recorded answers.");
+
+    let options = RunOptions {
+        part: Some(part),
+        inputs: Inputs::File(
+            input_dir
+                .join(format!("day{day}"))
+                .join(format!("{participant}.txt")),
+        ),
+        ..RunOptions::default()
+    };
+    let day = days::get(day).expect("Tests are only generated for registered days.");
+
+    match &day.run(&options)[..] {
+        [result] => match &result.outcome {
+            Outcome::Solved(answer) => assert_eq!(answer, expected),
+            outcome => panic!("Expected {expected}, got {outcome}"),
+        },
+        results => panic!("Expected a single result, got {}", results.len()),
+    }
+}
+
+#[test]
+fn checks_only_the_named_input_beside_a_per_part_one() {
+    let input_dir = env::temp_dir().join(format!("aoc2023-regression-{}", std::process::id()));
+    let day_dir = input_dir.join("day1");
+
+    let _ = fs::remove_dir_all(&input_dir);
+    fs::create_dir_all(&day_dir).unwrap();
+
+    fs::write(day_dir.join("xmp.txt"), "two1nine\nabcone2threexyz\n").unwrap();
+    fs::write(day_dir.join("xmp.answers"), "part2: 42\n").unwrap();
+    fs::write(day_dir.join("xmp.p2.txt"), "7pqrstsixteen\n").unwrap();
+
+    check_in(&input_dir, 1, Part::Two, "xmp");
+}
+
+include!(concat!(env!("OUT_DIR"), "/regression.rs"));
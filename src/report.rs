@@ -0,0 +1,311 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{
+    allocations,
+    runner::{self, Outcome, PartResult},
+    Answer,
+};
+
+/// How `aoc` prints its results.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// The `===== Begin Part 1 =====` blocks, one set per day.
+    #[default]
+    Text,
+    /// One JSON object per line, per result.
+    Json,
+    Csv,
+    /// A table ready to paste into the wiki.
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "`{s}` is not a format, expected text, json, csv or markdown."
+            )),
+        }
+    }
+}
+
+/// Prints results from any number of days, which must be grouped by day.
+pub fn print(results: &[PartResult], format: Format) {
+    match format {
+        Format::Text => {
+            for (index, day_results) in results.chunk_by(|a, b| a.day == b.day).enumerate() {
+                if index > 0 {
+                    println!();
+                }
+
+                println!("########## Day {} ##########", day_results[0].day);
+                runner::print_results(day_results);
+            }
+        }
+        Format::Json => results
+            .iter()
+            .for_each(|result| println!("{}", json(result))),
+        Format::Csv => {
+            if allocations::ENABLED {
+                println!("day,part,participant,status,answer,duration_ns,error,cached,allocations,allocated_bytes,peak_bytes");
+            } else {
+                println!("day,part,participant,status,answer,duration_ns,error,cached");
+            }
+
+            results
+                .iter()
+                .for_each(|result| println!("{}", csv(result)));
+        }
+        Format::Markdown => {
+            if allocations::ENABLED {
+                println!("| Day | Part | Participant | Answer | Time | Allocations |");
+                println!("| --: | --: | --- | --: | --: | --: |");
+            } else {
+                println!("| Day | Part | Participant | Answer | Time |");
+                println!("| --: | --: | --- | --: | --: |");
+            }
+            results
+                .iter()
+                .for_each(|result| println!("{}", markdown(result)));
+        }
+    }
+}
+
+fn status(outcome: &Outcome) -> &'static str {
+    match outcome {
+        Outcome::Solved(_) => "solved",
+        Outcome::Failed(_) => "failed",
+        Outcome::Skipped => "skipped",
+        Outcome::TimedOut(_) => "timeout",
+    }
+}
+
+fn json(result: &PartResult) -> String {
+    let answer = match &result.outcome {
+        Outcome::Solved(Answer::String(answer)) => json_string(answer),
+        Outcome::Solved(answer) => answer.to_string(),
+        _ => String::from("null"),
+    };
+
+    let error = match &result.outcome {
+        Outcome::Failed(error) => json_string(&error.to_string()),
+        _ => String::from("null"),
+    };
+
+    let allocations = match result.allocations {
+        Some(allocations) => format!(
+            r#"{{"count":{},"bytes":{},"peak_bytes":{}}}"#,
+            allocations.count, allocations.bytes, allocations.peak_bytes
+        ),
+        None => String::from("null"),
+    };
+
+    format!(
+        r#"{{"day":{},"part":{},"participant":{},"status":"{}","answer":{answer},"duration_ns":{},"error":{error},"cached":{},"allocations":{allocations}}}"#,
+        result.day,
+        result.part,
+        json_string(&result.participant),
+        status(&result.outcome),
+        result.duration.as_nanos(),
+        result.cached,
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+
+    for char in s.chars() {
+        match char {
+            '"' => escaped.push_str(r#"\""#),
+            '\\' => escaped.push_str(r"\\"),
+            '\n' => escaped.push_str(r"\n"),
+            '\r' => escaped.push_str(r"\r"),
+            '\t' => escaped.push_str(r"\t"),
+            char if char.is_control() => {
+                let _ = write!(escaped, r"\u{:04x}", char as u32);
+            }
+            char => escaped.push(char),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn csv(result: &PartResult) -> String {
+    let (answer, error) = match &result.outcome {
+        Outcome::Solved(answer) => (answer.to_string(), String::new()),
+        Outcome::Failed(error) => (String::new(), error.to_string()),
+        Outcome::Skipped | Outcome::TimedOut(_) => (String::new(), String::new()),
+    };
+
+    let mut fields = vec![
+        result.day.to_string(),
+        result.part.to_string(),
+        result.participant.clone(),
+        status(&result.outcome).to_string(),
+        answer,
+        result.duration.as_nanos().to_string(),
+        error,
+        result.cached.to_string(),
+    ];
+
+    if allocations::ENABLED {
+        match result.allocations {
+            Some(allocations) => fields.extend([
+                allocations.count.to_string(),
+                allocations.bytes.to_string(),
+                allocations.peak_bytes.to_string(),
+            ]),
+            None => fields.extend([String::new(), String::new(), String::new()]),
+        }
+    }
+
+    fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn markdown(result: &PartResult) -> String {
+    let (answer, time) = match &result.outcome {
+        Outcome::Solved(answer) if result.cached => (answer.to_string(), String::from("cached")),
+        Outcome::Solved(answer) => (answer.to_string(), format!("{:.2?}", result.duration)),
+        outcome => (outcome.to_string(), String::new()),
+    };
+
+    let row = format!(
+        "| {} | {} | {} | {} | {} |",
+        result.day,
+        result.part,
+        result.participant.to_uppercase(),
+        answer.replace('|', r"\|").replace('\n', " "),
+        time
+    );
+
+    if allocations::ENABLED {
+        let allocations = result
+            .allocations
+            .map(|allocations| allocations.to_string())
+            .unwrap_or_default();
+
+        format!("{row} {allocations} |")
+    } else {
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+    use crate::{AocError, Part};
+
+    fn result(participant: &str, outcome: Outcome) -> PartResult {
+        PartResult {
+            day: 3,
+            part: Part::Two,
+            participant: participant.to_owned(),
+            outcome,
+            duration: Duration::from_nanos(1500),
+            allocations: None,
+            cached: false,
+        }
+    }
+
+    fn solved(answer: impl Into<Answer>) -> PartResult {
+        result("abc", Outcome::Solved(answer.into()))
+    }
+
+    #[test]
+    fn writes_a_json_object_per_result() {
+        assert_eq!(
+            json(&solved(42)),
+            r#"{"day":3,"part":2,"participant":"abc","status":"solved","answer":42,"duration_ns":1500,"error":null,"cached":false,"allocations":null}"#
+        );
+        assert_eq!(
+            json(&result("abc", Outcome::Skipped)),
+            r#"{"day":3,"part":2,"participant":"abc","status":"skipped","answer":null,"duration_ns":1500,"error":null,"cached":false,"allocations":null}"#
+        );
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(
+            json_string("a \"quote\", a \\, a\nnewline, a\ttab and a \u{1}"),
+            r#""a \"quote\", a \\, a\nnewline, a\ttab and a \u0001""#
+        );
+        assert!(json(&solved(String::from("#.\n.#"))).contains(r##""answer":"#.\n.#""##));
+
+        let failed = result(
+            "abc",
+            Outcome::Failed(Arc::new(AocError::solve("Expected \"x\"."))),
+        );
+        assert!(json(&failed).contains(r#""status":"failed","answer":null"#));
+        assert!(json(&failed).contains(r#"Expected \"x\"."#));
+    }
+
+    #[test]
+    fn writes_a_csv_row_per_result() {
+        // Builds with allocations counted have three more columns, left empty here.
+        let extra = if allocations::ENABLED { ",,," } else { "" };
+
+        assert_eq!(
+            csv(&solved(42)),
+            format!("3,2,abc,solved,42,1500,,false{extra}")
+        );
+        assert_eq!(
+            csv(&result("abc", Outcome::TimedOut(Duration::from_secs(1)))),
+            format!("3,2,abc,timeout,,1500,,false{extra}")
+        );
+    }
+
+    #[test]
+    fn quotes_csv_fields_only_when_they_need_it() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn writes_a_markdown_row_per_result() {
+        let end = if allocations::ENABLED { "  |" } else { "" };
+
+        assert_eq!(
+            markdown(&solved(42)),
+            format!("| 3 | 2 | ABC | 42 | 1.50µs |{end}")
+        );
+        assert_eq!(
+            markdown(&PartResult {
+                cached: true,
+                ..solved(42)
+            }),
+            format!("| 3 | 2 | ABC | 42 | cached |{end}")
+        );
+    }
+
+    #[test]
+    fn keeps_markdown_answers_inside_their_cell() {
+        assert!(
+            markdown(&solved(String::from("a|b\nc|d"))).starts_with(r"| 3 | 2 | ABC | a\|b c\|d |")
+        );
+    }
+}
@@ -0,0 +1,618 @@
+use std::{
+    cell::OnceCell,
+    collections::BTreeSet,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    allocations::{self, Allocations},
+    cache::Cache,
+    cancel::{self, CancellationToken},
+    isolate::catch_panic,
+    pool, Answer, AocError, Part, Solution,
+};
+
+/// What came of solving one part of one day for one participant.
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub participant: String,
+    pub outcome: Outcome,
+    /// How long the part took to solve, not counting reading or parsing the input. Zero for an
+    /// answer from the cache.
+    pub duration: Duration,
+    /// What solving the part allocated, when allocations are being counted and it finished.
+    pub allocations: Option<Allocations>,
+    /// Whether the answer came from the [cache](Cache) rather than being solved again.
+    pub cached: bool,
+}
+
+#[derive(Clone)]
+pub enum Outcome {
+    Solved(Answer),
+    /// Shared, since an input that fails to parse fails both parts.
+    Failed(Arc<AocError>),
+    /// The participant has inputs for other days, but not this one.
+    Skipped,
+    /// The part was still running when its time budget ran out.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{answer}"),
+            Self::Failed(error) => write!(f, "FAILED - {error}"),
+            Self::Skipped => write!(f, "SKIPPED - no input file"),
+            Self::TimedOut(budget) => write!(f, "TIMEOUT - still running after {budget:.2?}"),
+        }
+    }
+}
+
+/// One part of a [`Solution`], working from its parsed input.
+pub(crate) type Solver<I> = fn(&I) -> Result<Answer, AocError>;
+
+/// An input as parsed, or the outcome both parts get instead.
+type ParsedInput<I> = Result<Arc<I>, Outcome>;
+
+/// How long [`solve_day`] gives each part, which is far longer than any of them should need, but
+/// stops a bad input from hanging the run.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Where a run's inputs come from.
+#[derive(Clone, Debug)]
+pub enum Inputs {
+    /// The `input/dayN/<initials>.txt` files for these participants, along with any
+    /// `<initials>.p1.txt` and `<initials>.p2.txt`, or for everyone with an input for any day if
+    /// the list is empty.
+    Participants(Vec<String>),
+    /// A single file from anywhere, reported under its file stem.
+    File(PathBuf),
+    /// Input that's already been read, e.g. from stdin.
+    Text { name: String, contents: String },
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Self::Participants(Vec::new())
+    }
+}
+
+/// Which parts a run solves, and for which inputs.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Only solve this part, rather than both.
+    pub part: Option<Part>,
+    pub inputs: Inputs,
+    /// How many inputs to solve at once. `None` means one per core.
+    pub jobs: Option<usize>,
+    /// How long each part gets before it's reported as timed out. With a budget, every part runs
+    /// on its own thread, and is asked to stop through [`cancel::check`] once time's up.
+    pub timeout: Option<Duration>,
+    /// Whether to say so on stderr when an input had to be [normalised](normalise).
+    pub warn_normalised: bool,
+    /// Where to look for answers before solving, and to keep new ones.
+    pub cache: Option<Arc<Cache>>,
+}
+
+impl RunOptions {
+    pub(crate) fn jobs(&self) -> usize {
+        self.jobs.unwrap_or_else(pool::default_jobs)
+    }
+
+    /// The solvers to run on the input called `name`: the parts that were asked for, less the
+    /// other part if the input is [only for one](only_part).
+    pub(crate) fn solvers<S: Solution>(&self, name: &str) -> Vec<(Part, Solver<S::Input>)> {
+        let solvers: [(Part, Solver<S::Input>); 2] = [(Part::One, S::part1), (Part::Two, S::part2)];
+
+        solvers
+            .into_iter()
+            .filter(|(part, _)| self.part.is_none_or(|only| only == *part))
+            .filter(|(part, _)| only_part(name).is_none_or(|only| only == *part))
+            .collect()
+    }
+}
+
+/// Reads each of the inputs a run covers, naming each after its participant, and
+/// [normalises](normalise) them. An input that can't be read comes back as the outcome both of its
+/// parts should get: skipped if a participant simply has no input for `day`, failed otherwise.
+pub(crate) fn read_inputs(day: u8, options: &RunOptions) -> Vec<(String, Result<String, Outcome>)> {
+    read_raw_inputs(day, &options.inputs)
+        .into_iter()
+        .map(|(name, contents)| {
+            let contents = contents.map(|contents| {
+                let (normalised, changes) = normalise(&contents);
+
+                if let (true, [first @ .., last]) = (options.warn_normalised, &changes[..]) {
+                    let removed = if first.is_empty() {
+                        last.to_string()
+                    } else {
+                        format!("{} and {last}", first.join(", "))
+                    };
+
+                    eprintln!(
+                        "Warning: removed {removed} from day {day}'s input for {}.",
+                        name.to_uppercase()
+                    );
+                }
+
+                normalised
+            });
+
+            (name, contents)
+        })
+        .collect()
+}
+
+fn read_raw_inputs(day: u8, inputs: &Inputs) -> Vec<(String, Result<String, Outcome>)> {
+    let participants = match inputs {
+        Inputs::Participants(participants) if participants.is_empty() => {
+            all_participants().into_iter().collect()
+        }
+        Inputs::Participants(participants) => participants.clone(),
+        Inputs::File(path) => {
+            let name = path.file_stem().map_or_else(
+                || path.display().to_string(),
+                |stem| stem.to_string_lossy().into(),
+            );
+
+            let contents = read_input(day, &name, path.clone(), false);
+
+            return vec![(name, contents)];
+        }
+        Inputs::Text { name, contents } => return vec![(name.clone(), Ok(contents.clone()))],
+    };
+
+    participants
+        .into_iter()
+        .flat_map(|participant| participant_inputs(day, participant))
+        .collect()
+}
+
+/// A participant's inputs for `day`: `<participant>.txt`, and `<participant>.p1.txt` and
+/// `<participant>.p2.txt` for inputs [only for one part](only_part), named after whichever of
+/// those files there are. Skipped, under the participant's own name, if there are none.
+fn participant_inputs(day: u8, participant: String) -> Vec<(String, Result<String, Outcome>)> {
+    let names = if only_part(&participant).is_some() {
+        vec![participant.clone()]
+    } else {
+        vec![
+            participant.clone(),
+            format!("{participant}.p1"),
+            format!("{participant}.p2"),
+        ]
+    };
+
+    let inputs: Vec<_> = names
+        .into_iter()
+        .map(|name| {
+            let path = PathBuf::from(format!("input/day{day}/{name}.txt"));
+            let contents = read_input(day, &name, path, true);
+
+            (name, contents)
+        })
+        .filter(|(_, contents)| !matches!(contents, Err(Outcome::Skipped)))
+        .collect();
+
+    if inputs.is_empty() {
+        vec![(participant, Err(Outcome::Skipped))]
+    } else {
+        inputs
+    }
+}
+
+/// Reads one input file. With `skip_missing`, a file that isn't there is skipped, as that just
+/// means there's no such input. Any other problem is a failure.
+fn read_input(day: u8, name: &str, path: PathBuf, skip_missing: bool) -> Result<String, Outcome> {
+    match fs::read_to_string(&path) {
+        Ok(contents) => Ok(contents),
+        Err(error) if skip_missing && error.kind() == io::ErrorKind::NotFound => {
+            Err(Outcome::Skipped)
+        }
+        Err(source) => Err(Outcome::Failed(Arc::new(
+            AocError::Io { path, source }.for_participant(day, None, name),
+        ))),
+    }
+}
+
+/// Puts an input into the one shape solvers can rely on: no byte order mark, `\n` line endings,
+/// no whitespace at the end of any line, and no blank lines at the end, but a final `\n` after
+/// the last line. Also returns what had to be removed to get there, if anything. A missing final
+/// `\n` isn't counted, as editors disagree about whether there should be one.
+pub fn normalise(input: &str) -> (String, Vec<&'static str>) {
+    let mut changes = Vec::new();
+
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(input) => {
+            changes.push("a byte order mark");
+            input
+        }
+        None => input,
+    };
+
+    if input.contains("\r\n") {
+        changes.push("CRLF line endings");
+    }
+
+    let mut normalised = String::with_capacity(input.len());
+    let mut trailing_whitespace = false;
+
+    for line in input.lines() {
+        let trimmed = line.trim_end();
+        trailing_whitespace |= trimmed.len() != line.len();
+
+        normalised.push_str(trimmed);
+        normalised.push('\n');
+    }
+
+    let content_len = normalised.trim_end().len();
+    trailing_whitespace |= normalised.len() > content_len + 1;
+    normalised.truncate(content_len);
+
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    if trailing_whitespace {
+        changes.push("trailing whitespace");
+    }
+
+    (normalised, changes)
+}
+
+/// The one part an input is for, if its name ends in `.p1` or `.p2`, as with `xmp.p2.txt` for an
+/// example that only part 2 uses. Other inputs are for both parts.
+pub fn only_part(name: &str) -> Option<Part> {
+    match name.rsplit_once('.')?.1 {
+        "p1" => Some(Part::One),
+        "p2" => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// Everyone with an input for at least one day, i.e. the names of the `input/dayN/*.txt` files,
+/// with any `.p1` or `.p2` left off, so that `xmp.p1` and `xmp.p2` are both `xmp`'s.
+pub fn all_participants() -> BTreeSet<String> {
+    let Ok(day_dirs) = fs::read_dir("input") else {
+        return BTreeSet::new();
+    };
+
+    day_dirs
+        .flatten()
+        .flat_map(|day_dir| participants_in(&day_dir.path()))
+        .map(|name| match name.rsplit_once('.') {
+            Some((participant, _)) if only_part(&name).is_some() => participant.to_owned(),
+            _ => name,
+        })
+        .collect()
+}
+
+fn participants_in(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+        .collect()
+}
+
+/// Solves the selected parts of `day` for each selected input, spreading the inputs over the
+/// worker pool. Anyone without an input for this day is reported as skipped. Results come back
+/// ordered by part, then participant, and a failure never stops the rest of the run.
+pub fn run_day<S: Solution>(day: u8, options: &RunOptions) -> Vec<PartResult> {
+    let results = pool::map(
+        options.jobs(),
+        read_inputs(day, options),
+        |(name, contents)| solve_input::<S>(day, options, &name, contents),
+    );
+
+    in_report_order(results.into_iter().flatten().collect())
+}
+
+/// Solves the selected parts for a single input, leaving out the other part if the input is
+/// [only for one](only_part). It's parsed at most once, and not at all if every part's answer is
+/// cached. A panic while parsing or solving is caught and reported as that part
+/// failing, like any other error.
+pub(crate) fn solve_input<S: Solution>(
+    day: u8,
+    options: &RunOptions,
+    name: &str,
+    contents: Result<String, Outcome>,
+) -> Vec<PartResult> {
+    let input: OnceCell<ParsedInput<S::Input>> = OnceCell::new();
+    let parse = || {
+        input.get_or_init(|| {
+            contents.clone().and_then(|contents| {
+                catch_panic(|| S::parse(&contents))
+                    .and_then(|input| input)
+                    .map(Arc::new)
+                    .map_err(|error| {
+                        Outcome::Failed(Arc::new(error.for_participant(day, None, name)))
+                    })
+            })
+        })
+    };
+
+    options
+        .solvers::<S>(name)
+        .into_iter()
+        .map(|(part, solve)| {
+            let cache = options.cache.as_ref().zip(contents.as_ref().ok());
+
+            if let Some(answer) = cache.and_then(|(cache, contents)| cache.get(day, part, contents))
+            {
+                return PartResult {
+                    day,
+                    part,
+                    participant: name.to_owned(),
+                    outcome: Outcome::Solved(answer),
+                    duration: Duration::ZERO,
+                    allocations: None,
+                    cached: true,
+                };
+            }
+
+            // Parsed before the clock starts, as it's not part of any one part's time.
+            let input = parse();
+            let start = Instant::now();
+
+            let answer = match (input, options.timeout) {
+                (Ok(input), None) => Ok(allocations::measure(|| {
+                    catch_panic(|| solve(input)).and_then(|answer| answer)
+                })),
+                (Ok(input), Some(budget)) => solve_within(solve, input, budget),
+                (Err(outcome), _) => Err(outcome.clone()),
+            };
+
+            let (outcome, allocations) = match answer {
+                Ok((Ok(answer), allocations)) => (Outcome::Solved(answer), allocations),
+                Ok((Err(error), allocations)) => (
+                    Outcome::Failed(Arc::new(error.for_participant(day, Some(part), name))),
+                    allocations,
+                ),
+                Err(outcome) => (outcome, None),
+            };
+
+            if let (Some((cache, contents)), Outcome::Solved(answer)) = (cache, &outcome) {
+                if let Err(error) = cache.insert(day, part, contents, answer) {
+                    eprintln!("Warning: couldn't cache an answer: {error}");
+                }
+            }
+
+            PartResult {
+                day,
+                part,
+                participant: name.to_owned(),
+                outcome,
+                duration: start.elapsed(),
+                allocations,
+                cached: false,
+            }
+        })
+        .collect()
+}
+
+/// Runs `solve` on its own thread, waiting at most `budget` for it. A part that runs out of time is
+/// cancelled and left to wind down on its own, as threads can't be stopped from the outside.
+fn solve_within<I: Send + Sync + 'static>(
+    solve: Solver<I>,
+    input: &Arc<I>,
+    budget: Duration,
+) -> Result<(Result<Answer, AocError>, Option<Allocations>), Outcome> {
+    let (sender, receiver) = mpsc::channel();
+    let token = CancellationToken::new();
+
+    let input = Arc::clone(input);
+    let part_token = token.clone();
+
+    thread::spawn(move || {
+        cancel::set_current(part_token);
+
+        // The runner may have stopped listening, in which case nobody needs the answer.
+        let _ = sender.send(allocations::measure(|| {
+            catch_panic(|| solve(&input)).and_then(|answer| answer)
+        }));
+    });
+
+    match receiver.recv_timeout(budget) {
+        Ok(answer) => Ok(answer),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+
+            Err(Outcome::TimedOut(budget))
+        }
+        Err(RecvTimeoutError::Disconnected) => Ok((
+            Err(AocError::solve(
+                "The solver's thread stopped without giving an answer.",
+            )),
+            None,
+        )),
+    }
+}
+
+/// Sorts results by day, then part, keeping participants in the order they were given.
+pub(crate) fn in_report_order(mut results: Vec<PartResult>) -> Vec<PartResult> {
+    results.sort_by_key(|result| (result.day, result.part));
+
+    results
+}
+
+/// Solves `day` for everyone, giving each part [`DEFAULT_TIMEOUT`] to finish.
+pub fn solve_day<S: Solution>(day: u8) {
+    let options = RunOptions {
+        timeout: Some(DEFAULT_TIMEOUT),
+        ..RunOptions::default()
+    };
+
+    print_results(&run_day::<S>(day, &options));
+}
+
+/// Prints a day's results as one block per part, with one line per participant.
+pub fn print_results(results: &[PartResult]) {
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| results.iter().any(|result| result.part == part));
+
+    for (index, part) in parts.enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        println!("===== Begin Part {part} =====");
+        results
+            .iter()
+            .filter(|result| result.part == part)
+            .for_each(|result| match &result.outcome {
+                Outcome::Solved(_) if result.cached => println!(
+                    "{}: {} (cached)",
+                    result.participant.to_uppercase(),
+                    result.outcome
+                ),
+                Outcome::Solved(_) => match result.allocations {
+                    Some(allocations) => println!(
+                        "{}: {} ({:.2?}, {allocations})",
+                        result.participant.to_uppercase(),
+                        result.outcome,
+                        result.duration
+                    ),
+                    None => println!(
+                        "{}: {} ({:.2?})",
+                        result.participant.to_uppercase(),
+                        result.outcome,
+                        result.duration
+                    ),
+                },
+                Outcome::Failed(_) | Outcome::Skipped | Outcome::TimedOut(_) => {
+                    println!("{}: {}", result.participant.to_uppercase(), result.outcome)
+                }
+            });
+        println!("=====  End Part {part}  =====");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+
+    static GAVE_UP: AtomicBool = AtomicBool::new(false);
+
+    /// Part 1 never finishes unless it's cancelled, and part 2 answers straight away.
+    struct Stuck;
+
+    impl Solution for Stuck {
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, AocError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<Answer, AocError> {
+            loop {
+                if let Err(error) = cancel::check() {
+                    GAVE_UP.store(true, Ordering::Relaxed);
+                    return Err(error);
+                }
+
+                thread::sleep(Duration::from_millis(5));
+            }
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Answer, AocError> {
+            Ok(Answer::from(2))
+        }
+    }
+
+    #[test]
+    fn leaves_normal_input_alone() {
+        assert_eq!(normalise("a\n b\n"), (String::from("a\n b\n"), vec![]));
+        assert_eq!(normalise(""), (String::new(), vec![]));
+    }
+
+    #[test]
+    fn adds_a_missing_final_newline_without_reporting_it() {
+        assert_eq!(normalise("a\nb"), (String::from("a\nb\n"), vec![]));
+    }
+
+    #[test]
+    fn strips_a_byte_order_mark() {
+        assert_eq!(
+            normalise("\u{feff}a\n"),
+            (String::from("a\n"), vec!["a byte order mark"])
+        );
+    }
+
+    #[test]
+    fn converts_crlf_line_endings() {
+        assert_eq!(
+            normalise("a\r\nb\r\n"),
+            (String::from("a\nb\n"), vec!["CRLF line endings"])
+        );
+    }
+
+    #[test]
+    fn trims_trailing_whitespace_and_blank_lines() {
+        let trimmed = (String::from("a\nb\n"), vec!["trailing whitespace"]);
+
+        assert_eq!(normalise("a \nb\t\n"), trimmed);
+        assert_eq!(normalise("a\nb\n\n\n"), trimmed);
+    }
+
+    #[test]
+    fn reports_the_lines_lost_from_whitespace_only_input() {
+        let emptied = (String::new(), vec!["trailing whitespace"]);
+
+        assert_eq!(normalise("\n\n\n"), emptied);
+        assert_eq!(normalise(" \n\t\n"), emptied);
+    }
+
+    #[test]
+    fn reports_every_change_in_order() {
+        assert_eq!(
+            normalise("\u{feff}a \r\nb\r\n\r\n").1,
+            [
+                "a byte order mark",
+                "CRLF line endings",
+                "trailing whitespace"
+            ]
+        );
+    }
+
+    #[test]
+    fn times_out_and_cancels_a_part_that_runs_too_long() {
+        let budget = Duration::from_millis(50);
+        let options = RunOptions {
+            timeout: Some(budget),
+            ..RunOptions::default()
+        };
+
+        let results = solve_input::<Stuck>(1, &options, "stuck", Ok(String::new()));
+
+        assert!(matches!(results[0].outcome, Outcome::TimedOut(timeout) if timeout == budget));
+        assert!(
+            matches!(&results[1].outcome, Outcome::Solved(answer) if *answer == Answer::from(2))
+        );
+
+        let waiting_since = Instant::now();
+
+        while !GAVE_UP.load(Ordering::Relaxed) {
+            assert!(
+                waiting_since.elapsed() < Duration::from_secs(5),
+                "The part wasn't cancelled."
+            );
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+}
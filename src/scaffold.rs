@@ -0,0 +1,304 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::AocError;
+
+/// `src/days/dayN.rs`, with `DAY` standing in for the day's number.
+const SOLVER_TEMPLATE: &str = r#"use crate::{Answer, AocError, Solution};
+
+pub struct DayDAY;
+
+impl Solution for DayDAY {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, AocError> {
+        part1(lines).map(Answer::from)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, AocError> {
+        part2(lines).map(Answer::from)
+    }
+}
+
+fn part1(_lines: &[String]) -> Result<u64, AocError> {
+    Err(AocError::solve("Part 1 hasn't been solved yet."))
+}
+
+fn part2(_lines: &[String]) -> Result<u64, AocError> {
+    Err(AocError::solve("Part 2 hasn't been solved yet."))
+}
+"#;
+
+/// `src/bin/dayN.rs`.
+const BIN_TEMPLATE: &str = r#"use aoc2023::{days::dayDAY::DayDAY, solve_day};
+
+fn main() {
+    solve_day::<DayDAY>(DAY);
+}
+"#;
+
+/// The two `.vscode/launch.json` configurations each day has, indented to sit in its list.
+const LAUNCH_TEMPLATE: &str = r#"        {
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug executable 'dayDAY'",
+            "cargo": {
+                "args": [
+                    "build",
+                    "--bin=dayDAY",
+                    "--package=aoc2023"
+                ],
+                "filter": {
+                    "name": "dayDAY",
+                    "kind": "bin"
+                }
+            },
+            "args": [],
+            "cwd": "${workspaceFolder}"
+        },
+        {
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug unit tests in executable 'dayDAY'",
+            "cargo": {
+                "args": [
+                    "test",
+                    "--no-run",
+                    "--bin=dayDAY",
+                    "--package=aoc2023"
+                ],
+                "filter": {
+                    "name": "dayDAY",
+                    "kind": "bin"
+                }
+            },
+            "args": [],
+            "cwd": "${workspaceFolder}"
+        }"#;
+
+/// Sets up a new day in the repository at `root`: a solver skeleton in `src/days/`, its binary in
+/// `src/bin/`, an empty `xmp.txt` in `input/dayN/` to paste the example into, an entry in
+/// [`DAYS`](crate::days::DAYS), and launch configurations if there's a `.vscode/launch.json`.
+/// Returns the files it created or changed. Refuses to touch anything if the day already exists.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, AocError> {
+    let solver_path = root.join("src").join("days").join(format!("day{day}.rs"));
+    let bin_path = root.join("src").join("bin").join(format!("day{day}.rs"));
+    let registry_path = root.join("src").join("days.rs");
+    let example_path = root.join("input").join(format!("day{day}")).join("xmp.txt");
+    let launch_path = root.join(".vscode").join("launch.json");
+
+    for path in [&solver_path, &bin_path] {
+        if path.exists() {
+            return Err(edit_error(path, format!("Day {day} already exists.")));
+        }
+    }
+
+    // Everything's worked out before anything's written, so a problem leaves the repository as it
+    // was.
+    let registry = register(&read(&registry_path)?, day)
+        .map_err(|message| edit_error(&registry_path, message))?;
+    let launch = match fs::read_to_string(&launch_path) {
+        Ok(launch) => Some(
+            add_launch_configurations(&launch, day)
+                .map_err(|message| edit_error(&launch_path, message))?,
+        )
+        .filter(|edited| *edited != launch),
+        Err(_) => None,
+    };
+
+    let fill = |template: &str| template.replace("DAY", &day.to_string());
+
+    write(&solver_path, &fill(SOLVER_TEMPLATE))?;
+    write(&bin_path, &fill(BIN_TEMPLATE))?;
+    write(&registry_path, &registry)?;
+
+    let mut touched = vec![solver_path, bin_path, registry_path];
+
+    if !example_path.exists() {
+        write(&example_path, "")?;
+        touched.push(example_path);
+    }
+
+    if let Some(launch) = launch {
+        write(&launch_path, &launch)?;
+        touched.push(launch_path);
+    }
+
+    Ok(touched)
+}
+
+/// Adds `pub mod dayN;` among the other days' modules, in order of day, which `rustfmt.toml` has
+/// `rustfmt` leave alone, and the day to the end of `DAYS`, making the array one longer.
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let module = format!("pub mod day{day}");
+
+    if registry
+        .lines()
+        .any(|line| line.trim() == format!("{module};"))
+    {
+        return Err(format!("Day {day} is already registered."));
+    }
+
+    let modules: Vec<usize> = registry
+        .match_indices("pub mod day")
+        .map(|(index, _)| index)
+        .collect();
+    let module_day = |index: usize| {
+        registry[index + "pub mod day".len()..]
+            .split(';')
+            .next()?
+            .parse::<u8>()
+            .ok()
+    };
+    let module_start = match modules
+        .iter()
+        .find(|&&index| module_day(index).is_some_and(|other| other > day))
+    {
+        Some(&index) => index,
+        None => modules
+            .last()
+            .and_then(|&index| Some(index + registry[index..].find('\n')? + 1))
+            .ok_or("Couldn't find the days' `pub mod` lines.")?,
+    };
+
+    let declaration = "pub const DAYS: [Day; ";
+    let length_start = registry
+        .find(declaration)
+        .ok_or("Couldn't find the declaration of `DAYS`.")?
+        + declaration.len();
+    let length_end = length_start
+        + registry[length_start..]
+            .find(']')
+            .ok_or("Couldn't find the length of `DAYS`.")?;
+    let length: usize = registry[length_start..length_end]
+        .parse()
+        .map_err(|_| String::from("Couldn't read the length of `DAYS`."))?;
+
+    let list_end = length_end
+        + registry[length_end..]
+            .find("\n];")
+            .ok_or("Couldn't find the end of `DAYS`.")?
+        + 1;
+
+    Ok([
+        &registry[..module_start],
+        &format!("{module};\n"),
+        &registry[module_start..length_start],
+        &(length + 1).to_string(),
+        &registry[length_end..list_end],
+        &format!("    Day::new::<day{day}::Day{day}>({day}),\n"),
+        &registry[list_end..],
+    ]
+    .concat())
+}
+
+/// Adds the day's configurations to the end of the `configurations` list, unless it has them
+/// already.
+fn add_launch_configurations(launch: &str, day: u8) -> Result<String, String> {
+    if launch.contains(&format!("'day{day}'")) {
+        return Ok(launch.to_owned());
+    }
+
+    let last_configuration_end = launch
+        .rfind("\n        }")
+        .ok_or("Couldn't find the last launch configuration.")?
+        + "\n        }".len();
+
+    Ok(format!(
+        "{},\n{}{}",
+        &launch[..last_configuration_end],
+        LAUNCH_TEMPLATE.replace("DAY", &day.to_string()),
+        &launch[last_configuration_end..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    let io_error = |source| AocError::Io {
+        path: path.to_owned(),
+        source,
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+
+    fs::write(path, contents).map_err(io_error)
+}
+
+fn edit_error(path: &Path, message: impl Into<String>) -> AocError {
+    AocError::Edit {
+        path: path.to_owned(),
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = include_str!("days.rs");
+    const LAUNCH: &str = include_str!("../.vscode/launch.json");
+
+    #[test]
+    fn registers_a_day_after_the_days_before_it() {
+        let registry = register(REGISTRY, 10).unwrap();
+
+        assert!(registry.contains("pub mod day9;\npub mod day10;\n"));
+        assert!(!registry.contains("pub mod day1;\npub mod day10;"));
+        assert!(
+            registry.contains("Day::new::<day9::Day9>(9),\n    Day::new::<day10::Day10>(10),\n];")
+        );
+    }
+
+    #[test]
+    fn registers_a_missing_day_before_the_days_after_it() {
+        let registry = register(&REGISTRY.replace("pub mod day5;\n", ""), 5).unwrap();
+
+        assert!(registry.contains("pub mod day4;\npub mod day5;\npub mod day6;\n"));
+    }
+
+    #[test]
+    fn makes_days_one_longer() {
+        let length = |registry: &str| {
+            let (_, rest) = registry.split_once("pub const DAYS: [Day; ").unwrap();
+            rest.split(']').next().unwrap().parse::<usize>().unwrap()
+        };
+
+        assert_eq!(
+            length(&register(REGISTRY, 10).unwrap()),
+            length(REGISTRY) + 1
+        );
+    }
+
+    #[test]
+    fn refuses_to_register_a_day_twice() {
+        assert!(register(REGISTRY, 1).is_err());
+        assert!(register(&register(REGISTRY, 10).unwrap(), 10).is_err());
+    }
+
+    #[test]
+    fn adds_launch_configurations_only_once() {
+        let configurations = |launch: &str| launch.matches("\"request\": \"launch\"").count();
+        let launch = add_launch_configurations(LAUNCH, 10).unwrap();
+
+        assert_eq!(configurations(&launch), configurations(LAUNCH) + 2);
+        assert_eq!(launch.matches("'day10'").count(), 2);
+        assert!(launch.contains("        },\n        {\n            \"type\": \"lldb\""));
+        assert!(launch.ends_with("        }\n    ]\n}"));
+
+        assert_eq!(add_launch_configurations(&launch, 10).unwrap(), launch);
+    }
+}
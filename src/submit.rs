@@ -0,0 +1,387 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{answers::ExpectedAnswers, config::Config, http, parse::Span, Answer, AocError, Part};
+
+/// How long to hold off after a wrong answer when the site doesn't say.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Which way a wrong answer was off, when the site says.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What came of trying to submit an answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Submission {
+    /// The site accepted it, and it's been recorded in the answers file.
+    Correct,
+    Wrong(Option<Hint>),
+    /// The site wants a break of this long before the next answer.
+    TooSoon(Duration),
+    /// The site has already had the right answer for this part.
+    AlreadySolved,
+    /// Not sent, as it's the recorded answer already.
+    AlreadyRecorded,
+    /// Not sent, as an earlier answer shows this one is wrong too.
+    KnownWrong {
+        rejected: Answer,
+        hint: Option<Hint>,
+    },
+    /// Not sent, as the break the site asked for has this long left to run.
+    CoolingDown(Duration),
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct, and recorded"),
+            Self::Wrong(None) => write!(f, "wrong"),
+            Self::Wrong(Some(hint)) => write!(f, "wrong, {hint}"),
+            Self::TooSoon(wait) => write!(f, "too soon, try again in {}s", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "already solved on the site"),
+            Self::AlreadyRecorded => write!(f, "not sent, it's the recorded answer already"),
+            Self::KnownWrong { hint: None, .. } => write!(f, "not sent, it was rejected before"),
+            Self::KnownWrong {
+                rejected,
+                hint: Some(hint),
+            } => write!(f, "not sent, {rejected} was already {hint}"),
+            Self::CoolingDown(wait) => {
+                write!(f, "not sent, {}s left before trying again", wait.as_secs())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// An answer the site turned down.
+#[derive(Clone, Debug)]
+struct Rejection {
+    day: u8,
+    part: Part,
+    answer: Answer,
+    hint: Option<Hint>,
+}
+
+impl Rejection {
+    /// Whether this rejection shows `answer` would be wrong too.
+    fn rules_out(&self, answer: &Answer) -> bool {
+        if self.answer == *answer {
+            return true;
+        }
+
+        match (self.hint, self.answer.as_i128(), answer.as_i128()) {
+            (Some(Hint::TooHigh), Some(rejected), Some(answer)) => answer >= rejected,
+            (Some(Hint::TooLow), Some(rejected), Some(answer)) => answer <= rejected,
+            _ => false,
+        }
+    }
+}
+
+/// Where `aoc` keeps what's been submitted. It's out of `input/`, as `build.rs` watches that and
+/// would rebuild after every submission, and out of `target/`, so `cargo clean` can't lose track
+/// of answers already rejected. It's kept out of git.
+pub fn default_history_dir() -> PathBuf {
+    PathBuf::from(".aoc")
+}
+
+/// What's been submitted for one participant, kept in `<history_dir>/<initials>.submissions` as
+/// `day 5 part 1: 1234 (too high)` lines for rejected answers, and a `cooldown until: <seconds>`
+/// line for when the site next accepts an answer, in seconds since the Unix epoch.
+#[derive(Debug, Default)]
+struct History {
+    rejections: Vec<Rejection>,
+    cooldown_until: Option<u64>,
+}
+
+impl History {
+    fn path(history_dir: &Path, initials: &str) -> PathBuf {
+        history_dir.join(format!("{initials}.submissions"))
+    }
+
+    fn load(path: &Path) -> Result<Self, AocError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+
+        let mut history = Self::default();
+
+        for line in Span::new(&contents).lines().filter(|line| !line.is_empty()) {
+            let (key, value) = line.key_value(':')?;
+
+            if key.as_str() == "cooldown until" {
+                history.cooldown_until = Some(value.parse()?);
+                continue;
+            }
+
+            let (day, part) = match key.words().collect::<Vec<_>>()[..] {
+                [day_label, day, part_label, part]
+                    if day_label.as_str() == "day" && part_label.as_str() == "part" =>
+                {
+                    (day, part)
+                }
+                _ => return Err(key.error("Expected `day <day> part <part>`.")),
+            };
+
+            let part = match part.as_str() {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(part.error(format!("`{part}` is not a part."))),
+            };
+
+            let (answer, hint) = match value.as_str().strip_suffix(" (too high)") {
+                Some(answer) => (answer, Some(Hint::TooHigh)),
+                None => match value.as_str().strip_suffix(" (too low)") {
+                    Some(answer) => (answer, Some(Hint::TooLow)),
+                    None => (value.as_str(), None),
+                },
+            };
+            let Ok(answer) = answer.parse();
+
+            history.rejections.push(Rejection {
+                day: day.parse()?,
+                part,
+                answer,
+                hint,
+            });
+        }
+
+        Ok(history)
+    }
+
+    fn save(&self, path: &Path) -> Result<(), AocError> {
+        let mut contents = String::new();
+
+        if let Some(cooldown_until) = self.cooldown_until {
+            contents.push_str(&format!("cooldown until: {cooldown_until}\n"));
+        }
+
+        for Rejection {
+            day,
+            part,
+            answer,
+            hint,
+        } in &self.rejections
+        {
+            contents.push_str(&format!("day {day} part {part}: {answer}"));
+
+            if let Some(hint) = hint {
+                contents.push_str(&format!(" ({hint})"));
+            }
+
+            contents.push('\n');
+        }
+
+        let io_error = |source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        fs::write(path, contents).map_err(io_error)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs())
+}
+
+/// Submits `answer` for one part of `day` as `initials`, unless it's already known how that would
+/// go: the answer is recorded as right, an earlier rejection rules it out, or the site's still
+/// waiting out a cooldown. Rejections and cooldowns are remembered under `history_dir`, and a
+/// right answer is recorded in the participant's answers file under `input_dir`.
+pub fn submit(
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    initials: &str,
+    config: &Config,
+    input_dir: &Path,
+    history_dir: &Path,
+) -> Result<Submission, AocError> {
+    let answers_path = ExpectedAnswers::path(input_dir, day, initials);
+    let mut answers = ExpectedAnswers::load_from(&answers_path)?;
+
+    if answers.get(part) == Some(answer) {
+        return Ok(Submission::AlreadyRecorded);
+    }
+
+    let history_path = History::path(history_dir, initials);
+    let mut history = History::load(&history_path)?;
+
+    if let Some(rejection) = history.rejections.iter().find(|rejection| {
+        rejection.day == day && rejection.part == part && rejection.rules_out(answer)
+    }) {
+        return Ok(Submission::KnownWrong {
+            rejected: rejection.answer.clone(),
+            hint: rejection.hint,
+        });
+    }
+
+    if let Some(wait) = history
+        .cooldown_until
+        .and_then(|cooldown_until| cooldown_until.checked_sub(now()))
+        .filter(|&wait| wait > 0)
+    {
+        return Ok(Submission::CoolingDown(Duration::from_secs(wait)));
+    }
+
+    let url = format!("{}/2023/day/{day}/answer", config.base_url);
+    let cookie = format!("session={}", config.session()?);
+    let form = format!("level={part}&answer={}", form_encode(&answer.to_string()));
+
+    let response = http::request("POST", &url, &[("Cookie", &cookie)], Some(&form))?;
+
+    if response.status != 200 {
+        return Err(AocError::Http {
+            url,
+            message: format!(
+                "{}: {}",
+                response.status,
+                response.body.trim().lines().next().unwrap_or_default()
+            ),
+        });
+    }
+
+    let submission = read_response(&response.body).ok_or_else(|| AocError::Http {
+        url,
+        message: String::from("The response didn't say whether the answer was right."),
+    })?;
+
+    match &submission {
+        Submission::Correct => {
+            if let Some(day_dir) = answers_path.parent() {
+                fs::create_dir_all(day_dir).map_err(|source| AocError::Io {
+                    path: day_dir.to_owned(),
+                    source,
+                })?;
+            }
+
+            answers.set(part, answer.clone());
+            answers.save_to(&answers_path)?;
+        }
+        Submission::Wrong(hint) => {
+            history.rejections.push(Rejection {
+                day,
+                part,
+                answer: answer.clone(),
+                hint: *hint,
+            });
+            history.cooldown_until = Some(
+                now()
+                    + wait_after_wrong(&response.body)
+                        .unwrap_or(DEFAULT_COOLDOWN)
+                        .as_secs(),
+            );
+        }
+        Submission::TooSoon(wait) => history.cooldown_until = Some(now() + wait.as_secs()),
+        _ => {}
+    }
+
+    if !matches!(submission, Submission::Correct | Submission::AlreadySolved) {
+        history.save(&history_path)?;
+    }
+
+    Ok(submission)
+}
+
+/// Works out what the site made of an answer from the page it sent back.
+fn read_response(body: &str) -> Option<Submission> {
+    let body = body.to_lowercase();
+
+    if body.contains("that's the right answer") {
+        Some(Submission::Correct)
+    } else if body.contains("that's not the right answer") {
+        Some(Submission::Wrong(
+            if body.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if body.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            },
+        ))
+    } else if body.contains("you gave an answer too recently") {
+        // "You have 1m 23s left to wait."
+        let wait = body
+            .split_once("you have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| {
+                wait.split_whitespace()
+                    .filter_map(|amount| {
+                        let (number, unit) =
+                            amount.split_at(amount.find(|char: char| !char.is_ascii_digit())?);
+                        let number: u64 = number.parse().ok()?;
+
+                        match unit {
+                            "m" => Some(number * 60),
+                            "s" => Some(number),
+                            _ => None,
+                        }
+                    })
+                    .sum()
+            })
+            .map_or(DEFAULT_COOLDOWN, Duration::from_secs);
+
+        Some(Submission::TooSoon(wait))
+    } else if body.contains("you don't seem to be solving the right level") {
+        Some(Submission::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+/// How long the site asked for before the next answer, after a wrong one, e.g. "please wait one
+/// minute before trying again" or "please wait 5 minutes before trying again".
+fn wait_after_wrong(body: &str) -> Option<Duration> {
+    let body = body.to_lowercase();
+    let (_, rest) = body.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+
+    let minutes = match words.next()? {
+        "one" | "a" => 1,
+        number => number.parse().ok()?,
+    };
+
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
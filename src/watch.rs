@@ -0,0 +1,127 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    fs,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    days::{self, Day},
+    report::{self, Format},
+    runner::{Inputs, PartResult, RunOptions},
+    Part,
+};
+
+/// The modification time and a hash of the contents of every file a watch covers, so that editing,
+/// adding or removing any of them can be spotted.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Snapshot(BTreeMap<PathBuf, (Option<SystemTime>, u64)>);
+
+impl Snapshot {
+    /// Everything in `input/dayN/` for each day, or just the one file for [`Inputs::File`].
+    /// [`Inputs::Text`] has nothing to watch.
+    fn take(days: &[&Day], inputs: &Inputs) -> Self {
+        let paths: Vec<PathBuf> = match inputs {
+            Inputs::Participants(_) => days
+                .iter()
+                .filter_map(|day| fs::read_dir(format!("input/day{}", day.number)).ok())
+                .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+                .filter(|path| path.is_file())
+                .collect(),
+            Inputs::File(path) => vec![path.clone()],
+            Inputs::Text { .. } => Vec::new(),
+        };
+
+        Self(
+            paths
+                .into_iter()
+                .filter_map(|path| {
+                    let modified = fs::metadata(&path).ok()?.modified().ok();
+
+                    let mut hasher = DefaultHasher::new();
+                    fs::read(&path).ok()?.hash(&mut hasher);
+
+                    Some((path, (modified, hasher.finish())))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Solves `days` and prints the results, then solves them again every time one of their input
+/// files changes, checking every `interval`. Each re-run is followed by how the answers changed
+/// from the run before. Only stops when the process is killed.
+pub fn watch(days: &[&Day], options: &RunOptions, format: Format, interval: Duration) -> ! {
+    let mut last_snapshot = Snapshot::take(days, &options.inputs);
+    let mut last_results = days::run(days, options);
+
+    report::print(&last_results, format);
+    eprintln!("\nWatching for changes to the inputs...");
+
+    loop {
+        thread::sleep(interval);
+
+        let snapshot = Snapshot::take(days, &options.inputs);
+
+        if snapshot == last_snapshot {
+            continue;
+        }
+
+        let results = days::run(days, options);
+
+        println!();
+        report::print(&results, format);
+        println!();
+        print_changes(&last_results, &results);
+        eprintln!("\nWatching for changes to the inputs...");
+
+        last_snapshot = snapshot;
+        last_results = results;
+    }
+}
+
+/// Prints a line for each part whose outcome differs between two runs, including parts only one of
+/// the runs has.
+fn print_changes(previous: &[PartResult], current: &[PartResult]) {
+    let outcomes = |results: &[PartResult]| -> BTreeMap<(u8, Part, String), String> {
+        results
+            .iter()
+            .map(|result| {
+                let key = (result.day, result.part, result.participant.clone());
+
+                (key, result.outcome.to_string())
+            })
+            .collect()
+    };
+
+    let previous = outcomes(previous);
+    let current = outcomes(current);
+
+    let mut keys: Vec<_> = previous.keys().chain(current.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let changes: Vec<String> = keys
+        .into_iter()
+        .filter_map(|key @ (day, part, participant)| {
+            let before = previous.get(key).map_or("nothing", String::as_str);
+            let after = current.get(key).map_or("nothing", String::as_str);
+
+            (before != after).then(|| {
+                format!(
+                    "Day {day} part {part} {}: {before} -> {after}",
+                    participant.to_uppercase()
+                )
+            })
+        })
+        .collect();
+
+    if changes.is_empty() {
+        println!("No answers changed since the last run.");
+    } else {
+        println!("Changes since the last run:");
+        changes.iter().for_each(|change| println!("{change}"));
+    }
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::fs;
+
+use aoc2023::{examples, Answer, AocError, Part};
+
+/// Trimmed down from day 1's page, which has a different example for each part.
+const DAY1: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+<p>Consider your entire calibration document. <em>What is the sum of all of the calibration values?</em></p>
+</article>
+<p>Your puzzle answer was <code>55607</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>For example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+/// Shaped like a page where part 2 only changes the rules, so reuses part 1's example.
+const SHARED: &str = r#"<article class="day-desc"><p>For example:</p>
+<pre><code>a -&gt; b &amp; <em>c</em>
+</code></pre>
+<p>That makes <em><code>6</code></em> in total.</p></article>
+<article class="day-desc"><p>Now, the example gives <code><em>8</em></code>.</p></article>"#;
+
+#[test]
+fn gives_each_part_its_own_example_when_they_differ() {
+    let found = examples::extract(DAY1);
+
+    assert_eq!(found.len(), 2);
+
+    assert_eq!(found[0].name, "xmp.p1");
+    assert!(found[0].input.starts_with("1abc2\n"));
+    assert!(found[0].input.ends_with("treb7uchet\n"));
+    assert_eq!(found[0].answers.get(Part::One), Some(&Answer::from(142)));
+    assert_eq!(found[0].answers.get(Part::Two), None);
+
+    assert_eq!(found[1].name, "xmp.p2");
+    assert_eq!(found[1].input.lines().count(), 7);
+    assert_eq!(found[1].answers.get(Part::One), None);
+    assert_eq!(found[1].answers.get(Part::Two), Some(&Answer::from(281)));
+}
+
+#[test]
+fn adds_the_second_answer_to_a_shared_example() {
+    let found = examples::extract(SHARED);
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "xmp");
+    assert_eq!(found[0].input, "a -> b & c\n");
+    assert_eq!(found[0].answers.get(Part::One), Some(&Answer::from(6)));
+    assert_eq!(found[0].answers.get(Part::Two), Some(&Answer::from(8)));
+}
+
+#[test]
+fn saves_examples_without_overwriting_different_ones() {
+    let day_dir = common::temp_dir("examples").join("day1");
+
+    let written = examples::save(&examples::extract(DAY1), &day_dir).unwrap();
+
+    assert_eq!(written.len(), 4);
+    assert_eq!(
+        fs::read_to_string(day_dir.join("xmp.p2.answers")).unwrap(),
+        "part2: 281\n"
+    );
+
+    // Saving the same examples again has nothing left to do.
+    assert!(examples::save(&examples::extract(DAY1), &day_dir)
+        .unwrap()
+        .is_empty());
+
+    fs::write(day_dir.join("xmp.p1.txt"), "something else\n").unwrap();
+
+    assert!(matches!(
+        examples::save(&examples::extract(DAY1), &day_dir),
+        Err(AocError::Edit { .. })
+    ));
+}
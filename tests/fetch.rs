@@ -0,0 +1,66 @@
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use aoc2023::{
+    config::Config,
+    fetch::{fetch, Fetched},
+    AocError,
+};
+use common::MockServer;
+
+fn config(base_url: &str) -> Config {
+    Config::parse(
+        PathBuf::from("test-config"),
+        &format!("session = abc123\nbase_url = {base_url}/\n"),
+    )
+    .unwrap()
+}
+
+#[test]
+fn downloads_an_input_once_then_uses_the_cached_copy() {
+    let server = MockServer::start(|_| (200, String::from("1 2 3\n")));
+    let input_dir = common::temp_dir("fetch-cached");
+    let config = config(&server.base_url);
+
+    let path = input_dir.join("day9").join("abc.txt");
+
+    assert_eq!(
+        fetch(9, "abc", &config, &input_dir).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+    assert_eq!(
+        fetch(9, "abc", &config, &input_dir).unwrap(),
+        Fetched::Cached(path)
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/9/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+}
+
+#[test]
+fn leaves_nothing_behind_when_the_site_refuses() {
+    let server = MockServer::start(|_| (404, String::from("Not unlocked yet.")));
+    let input_dir = common::temp_dir("fetch-refused");
+
+    let error = fetch(25, "abc", &config(&server.base_url), &input_dir).unwrap_err();
+
+    assert!(matches!(error, AocError::Http { .. }), "{error}");
+    assert!(error.to_string().contains("404"), "{error}");
+    assert!(!input_dir.join("day25").join("abc.txt").exists());
+}
+
+#[test]
+fn needs_a_session() {
+    let input_dir = common::temp_dir("fetch-no-session");
+    let config = Config::parse(PathBuf::from("test-config"), "initials = abc\n").unwrap();
+
+    let error = fetch(1, "abc", &config, &input_dir).unwrap_err();
+
+    assert!(matches!(error, AocError::Config { .. }), "{error}");
+}
@@ -0,0 +1,187 @@
+mod common;
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use aoc2023::{
+    config::Config,
+    submit::{submit, Hint, Submission},
+    Answer, Part,
+};
+use common::MockServer;
+
+fn config(base_url: &str) -> Config {
+    Config::parse(
+        PathBuf::from("test-config"),
+        &format!("session = abc123\nbase_url = {base_url}\n"),
+    )
+    .unwrap()
+}
+
+fn page(message: &str) -> String {
+    format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+}
+
+#[test]
+fn records_a_right_answer_and_never_sends_it_again() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page("That's the right answer! You are one gold star closer."),
+        )
+    });
+    let input_dir = common::temp_dir("submit-right");
+    let history_dir = common::temp_dir("submit-right-history");
+    let config = config(&server.base_url);
+
+    let answer = Answer::from(42);
+
+    assert_eq!(
+        submit(
+            5,
+            Part::One,
+            &answer,
+            "abc",
+            &config,
+            &input_dir,
+            &history_dir
+        )
+        .unwrap(),
+        Submission::Correct
+    );
+    assert_eq!(
+        fs::read_to_string(input_dir.join("day5").join("abc.answers")).unwrap(),
+        "part1: 42\n"
+    );
+
+    assert_eq!(
+        submit(
+            5,
+            Part::One,
+            &answer,
+            "abc",
+            &config,
+            &input_dir,
+            &history_dir
+        )
+        .unwrap(),
+        Submission::AlreadyRecorded
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/5/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+    assert_eq!(requests[0].body, "level=1&answer=42");
+}
+
+#[test]
+fn refuses_answers_ruled_out_by_earlier_ones_and_waits_out_the_cooldown() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page(
+                "That's not the right answer; your answer is too high. Please wait one minute \
+                 before trying again.",
+            ),
+        )
+    });
+    let input_dir = common::temp_dir("submit-wrong");
+    let history_dir = common::temp_dir("submit-wrong-history").join(".aoc");
+    let config = config(&server.base_url);
+
+    assert_eq!(
+        submit(
+            7,
+            Part::Two,
+            &Answer::from(100),
+            "abc",
+            &config,
+            &input_dir,
+            &history_dir
+        )
+        .unwrap(),
+        Submission::Wrong(Some(Hint::TooHigh))
+    );
+
+    assert_eq!(
+        submit(
+            7,
+            Part::Two,
+            &Answer::from(150),
+            "abc",
+            &config,
+            &input_dir,
+            &history_dir
+        )
+        .unwrap(),
+        Submission::KnownWrong {
+            rejected: Answer::from(100),
+            hint: Some(Hint::TooHigh)
+        }
+    );
+
+    // Part 1 doesn't share part 2's rejections, but does share the cooldown.
+    let Submission::CoolingDown(wait) = submit(
+        7,
+        Part::One,
+        &Answer::from(150),
+        "abc",
+        &config,
+        &input_dir,
+        &history_dir,
+    )
+    .unwrap() else {
+        panic!("Expected to be cooling down.");
+    };
+    assert!(wait <= Duration::from_secs(60) && wait >= Duration::from_secs(55));
+
+    assert_eq!(server.requests().len(), 1);
+
+    // The history is kept where it was asked to be, and not among the inputs.
+    assert!(history_dir.join("abc.submissions").is_file());
+    assert!(!input_dir.join("abc.submissions").exists());
+}
+
+#[test]
+fn remembers_how_long_the_site_asked_to_wait() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait."),
+        )
+    });
+    let input_dir = common::temp_dir("submit-too-soon");
+    let history_dir = common::temp_dir("submit-too-soon-history");
+    let config = config(&server.base_url);
+
+    assert_eq!(
+        submit(
+            1,
+            Part::One,
+            &Answer::from(1),
+            "abc",
+            &config,
+            &input_dir,
+            &history_dir
+        )
+        .unwrap(),
+        Submission::TooSoon(Duration::from_secs(83))
+    );
+
+    let Submission::CoolingDown(wait) = submit(
+        1,
+        Part::One,
+        &Answer::from(2),
+        "abc",
+        &config,
+        &input_dir,
+        &history_dir,
+    )
+    .unwrap() else {
+        panic!("Expected to be cooling down.");
+    };
+    assert!(wait <= Duration::from_secs(83) && wait >= Duration::from_secs(78));
+
+    assert_eq!(server.requests().len(), 1);
+}